@@ -20,6 +20,7 @@ pub struct Config {
     pub chain_type: ChainType,
 }
 
+#[allow(clippy::new_without_default)]
 impl Config {
     // Deserializes config from file
     pub fn new() -> Self {
@@ -0,0 +1,97 @@
+use sha2::{Digest, Sha256};
+use std::{path::Path, process::Command};
+
+/// Framework package reported as the compiler version
+const FRAMEWORK_PACKAGE: &str = "multiversx-sc";
+
+/// Audit scope collected from a local git checkout
+#[derive(Debug)]
+pub struct GitScope {
+    pub repository_url: String,
+    pub commit_hash: Vec<u8>,
+    pub compiler_version: String,
+    pub files: Vec<(String, [u8; 32])>,
+}
+
+impl GitScope {
+    // Reads the remote, HEAD commit, tracked files and framework version of a clean checkout.
+    // File hashes are taken from the committed blobs, so they always match the recorded commit.
+    pub fn from_checkout(checkout_dir: &str, pathspecs: &[String]) -> Self {
+        let status = git(checkout_dir, &["status", "--porcelain"]);
+        assert!(
+            status.trim().is_empty(),
+            "checkout has uncommitted changes, commit or stash them first:\n{status}"
+        );
+
+        let repository_url = git(checkout_dir, &["config", "--get", "remote.origin.url"]);
+        let commit_hex = git(checkout_dir, &["rev-parse", "HEAD"]);
+        let commit_hash = hex::decode(commit_hex.trim()).expect("invalid commit hash");
+
+        let mut ls_files_args = vec!["ls-files", "-z", "--"];
+        ls_files_args.extend(pathspecs.iter().map(String::as_str));
+        let files = git(checkout_dir, &ls_files_args)
+            .split_terminator('\0')
+            .map(|path| {
+                let content = git_bytes(checkout_dir, &["cat-file", "blob", &format!("HEAD:{path}")]);
+                (path.to_string(), Sha256::digest(&content).into())
+            })
+            .collect();
+
+        GitScope {
+            repository_url: strip_credentials(repository_url.trim()),
+            commit_hash,
+            compiler_version: framework_version(checkout_dir),
+            files,
+        }
+    }
+}
+
+// Removes any `user:token@` part from a URL remote, since the scope is published on-chain.
+// scp-like remotes (`git@host:path`) carry no secret and are kept as they are.
+pub fn strip_credentials(url: &str) -> String {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return url.to_string();
+    };
+    let authority_end = rest.find('/').unwrap_or(rest.len());
+    match rest[..authority_end].rfind('@') {
+        Some(at) => format!("{scheme}://{}", &rest[at + 1..]),
+        None => url.to_string(),
+    }
+}
+
+// Runs a git command in the checkout and returns its stdout
+fn git(checkout_dir: &str, args: &[&str]) -> String {
+    String::from_utf8(git_bytes(checkout_dir, args)).unwrap()
+}
+
+fn git_bytes(checkout_dir: &str, args: &[&str]) -> Vec<u8> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(checkout_dir)
+        .args(args)
+        .output()
+        .expect("failed to run git");
+    assert!(output.status.success(), "git {} failed", args.join(" "));
+    output.stdout
+}
+
+// Looks up the framework version in the checkout's Cargo.lock, if there is one
+fn framework_version(checkout_dir: &str) -> String {
+    let lock_path = Path::new(checkout_dir).join("Cargo.lock");
+    let Ok(content) = std::fs::read_to_string(lock_path) else {
+        return String::new();
+    };
+    let lock: toml::Value = toml::from_str(&content).unwrap();
+
+    lock.get("package")
+        .and_then(|packages| packages.as_array())
+        .and_then(|packages| {
+            packages.iter().find(|package| {
+                package.get("name").and_then(|name| name.as_str()) == Some(FRAMEWORK_PACKAGE)
+            })
+        })
+        .and_then(|package| package.get("version"))
+        .and_then(|version| version.as_str())
+        .map(|version| format!("{FRAMEWORK_PACKAGE} {version}"))
+        .unwrap_or_default()
+}
@@ -1,17 +1,22 @@
 #![allow(non_snake_case)]
 
 pub mod config;
+pub mod git_scope;
 mod proxy;
 
 use config::Config;
+use git_scope::GitScope;
 use multiversx_sc_snippets::imports::*;
 use serde::{Deserialize, Serialize};
+use multiversx_sc_snippets::multiversx_sc_scenario::multiversx_chain_vm::crypto_functions::keccak256;
+use smart_contract::{commitment::AuditReveal, MAX_BATCH_SIZE};
 use std::{
     io::{Read, Write},
     path::Path,
 };
 
 const STATE_FILE: &str = "state.toml";
+const BATCH_FILE: &str = "audits_batch.toml";
 
 pub async fn smart_contract_cli() {
     env_logger::init();
@@ -19,18 +24,199 @@ pub async fn smart_contract_cli() {
     let mut args = std::env::args();
     let _ = args.next();
     let cmd = args.next().expect("at least one argument required");
+    // Hashed locally before any connection, so the salt never reaches the gateway
+    if cmd == "compute_audit_commitment" {
+        let auditor = args.next().expect("auditor address required");
+        let contract_hash = args.next().expect("contract hash required");
+        let stars = args
+            .next()
+            .expect("stars required")
+            .parse()
+            .expect("stars must be a number");
+        let summary = args.next().expect("summary required");
+        let salt = args.next().expect("salt required");
+        let commitment = compute_audit_commitment(&auditor, &contract_hash, stars, &summary, &salt);
+        println!("Commitment: {}", hex::encode(commitment));
+        return;
+    }
+
     let config = Config::new();
     let mut interact = ContractInteract::new(config).await;
     match cmd.as_str() {
         "deploy" => interact.deploy().await,
         "register_audit" => interact.register_audit().await,
+        "register_audits_batch" => {
+            let batch_file = args.next().unwrap_or_else(|| BATCH_FILE.to_string());
+            interact.register_audits_batch(&batch_file).await
+        },
+        "migrate_legacy_audits" => {
+            let contract_hashes: Vec<String> = args.collect();
+            assert!(!contract_hashes.is_empty(), "at least one contract hash required");
+            interact.migrate_legacy_audits(&contract_hashes).await
+        },
         "get_all_audits" => interact.get_all_audits().await,
         "getTotalContracts" => interact.get_total_contracts().await,
         "getContractAudits" => interact.get_contract_audits().await,
         "getAuditorHistory" => interact.get_auditor_history().await,
         "getLatestAudit" => interact.get_latest_audit().await,
-        "withdraw" => interact.withdraw().await,
+        "queue_withdraw" => interact.queue_withdraw().await,
         "getOwner" => interact.owner().await,
+        "getLockedFunds" => interact.locked_funds().await,
+        "create_bounty" => interact.create_bounty().await,
+        "fund_bounty" => interact.fund_bounty().await,
+        "submit_vulnerability_report" => interact.submit_vulnerability_report().await,
+        "approve_report" => interact.approve_report().await,
+        "reject_report" => interact.reject_report().await,
+        "close_bounty" => interact.close_bounty().await,
+        "getBountyPool" => interact.get_bounty_pool().await,
+        "getBountyReports" => interact.get_bounty_reports().await,
+        "getActiveBounties" => interact.bounty_contracts().await,
+        "register_chain_audit" => interact.register_chain_audit().await,
+        "getAllAuditsByChain" => interact.get_all_audits_by_chain().await,
+        "getTotalContractsByChain" => interact.get_total_contracts_by_chain().await,
+        "getContractKey" => interact.get_contract_key().await,
+        "getContractNamespace" => interact.get_contract_namespace().await,
+        "getKnownChains" => interact.known_chains().await,
+        "set_audit_scope" => {
+            let checkout_dir = args.next().expect("checkout directory required");
+            let contract_hash = args.next().expect("contract hash required");
+            let audit_index = args
+                .next()
+                .expect("audit index required")
+                .parse()
+                .expect("audit index must be a number");
+            let pathspecs: Vec<String> = args.collect();
+            interact
+                .set_audit_scope(&checkout_dir, &contract_hash, audit_index, &pathspecs)
+                .await
+        },
+        "getAuditScope" => interact.get_audit_scope().await,
+        "add_moderator" => interact.add_moderator().await,
+        "remove_moderator" => interact.remove_moderator().await,
+        "hide_audit" => interact.hide_audit().await,
+        "unhide_audit" => interact.unhide_audit().await,
+        "hide_contract" => interact.hide_contract().await,
+        "unhide_contract" => interact.unhide_contract().await,
+        "block_auditor" => interact.block_auditor().await,
+        "unblock_auditor" => interact.unblock_auditor().await,
+        "isAuditHidden" => interact.is_audit_hidden().await,
+        "isContractHidden" => interact.hidden_contract().await,
+        "getModerationLogLength" => interact.get_moderation_log_length().await,
+        "getModerationLog" => interact.get_moderation_log().await,
+        "getModerators" => interact.moderators().await,
+        "getBlockedAuditors" => interact.blocked_auditors().await,
+        "getVisibleAudits" => interact.get_visible_audits().await,
+        "getVisibleAuditsByChain" => interact.get_visible_audits_by_chain().await,
+        "getVisibleContractAudits" => interact.get_visible_contract_audits().await,
+        "getVisibleAuditorHistory" => interact.get_visible_auditor_history().await,
+        "getLatestVisibleAudit" => interact.get_latest_visible_audit().await,
+        "queue_set_rate_limits" => interact.queue_set_rate_limits().await,
+        "getRemainingQuota" => interact.get_remaining_quota().await,
+        "getNextAllowedAuditTime" => interact.get_next_allowed_audit_time().await,
+        "getRateLimits" => interact.get_rate_limits().await,
+        "review_audit" => interact.review_audit().await,
+        "getAuditReviews" => interact.get_audit_reviews().await,
+        "getAuditorReputation" => interact.get_auditor_reputation().await,
+        "set_governance_token" => interact.set_governance_token().await,
+        "queue_set_voting_period" => interact.queue_set_voting_period().await,
+        "vote_on_audit" => interact.vote_on_audit().await,
+        "unlock_votes" => interact.unlock_votes().await,
+        "getVotingWindowEnd" => interact.get_voting_window_end().await,
+        "getGovernanceToken" => interact.governance_token().await,
+        "getVotingPeriod" => interact.voting_period().await,
+        "getAuditVotes" => interact.get_audit_votes().await,
+        "getVoteLock" => interact.get_vote_lock().await,
+        "propose" => interact.propose().await,
+        "vote_proposal" => interact.vote_proposal().await,
+        "execute_proposal" => interact.execute_proposal().await,
+        "veto_proposal" => interact.veto_proposal().await,
+        "getProposal" => interact.get_proposal().await,
+        "getProposalCount" => interact.get_proposal_count().await,
+        "getProposalStatus" => interact.get_proposal_status().await,
+        "hasVotedOnProposal" => interact.has_voted_on_proposal().await,
+        "getRegistrationFee" => interact.registration_fee().await,
+        "getAuditValidityPeriod" => interact.audit_validity_period().await,
+        "isAdmissionRequired" => interact.admission_required().await,
+        "getAdmittedAuditors" => interact.admitted_auditors().await,
+        "getMaxSummaryLength" => interact.get_max_summary_length().await,
+        "isAuditValid" => interact.is_audit_valid().await,
+        "queue_admin_action" => interact.queue_admin_action().await,
+        "cancel_admin_action" => interact.cancel_admin_action().await,
+        "execute_admin_action" => interact.execute_admin_action().await,
+        "getAdminAction" => interact.get_admin_action().await,
+        "getQueuedActions" => interact.get_queued_actions().await,
+        "getTimelockDelay" => interact.get_timelock_delay().await,
+        "getApprovedUpgrade" => interact.approved_upgrade().await,
+        "claim_rewards" => interact.claim_rewards().await,
+        "getClaimableRewards" => interact.get_claimable_rewards().await,
+        "getTotalClaimableRewards" => interact.get_total_claimable_rewards().await,
+        "getAuditorShare" => interact.auditor_share_bps().await,
+        "link_project" => interact.link_project().await,
+        "unlink_project" => interact.unlink_project().await,
+        "respond_to_audit" => interact.respond_to_audit().await,
+        "getProjectLink" => interact.get_project_link().await,
+        "getAuditResponse" => interact.get_audit_response().await,
+        "getRemediationAudits" => interact.get_remediation_audits().await,
+        "getVisibleRemediationAudits" => interact.get_visible_remediation_audits().await,
+        "record_upgrade" => interact.record_upgrade().await,
+        "getContractLineage" => interact.get_contract_lineage().await,
+        "getLineageAudits" => interact.get_lineage_audits().await,
+        "getVisibleLineageAudits" => interact.get_visible_lineage_audits().await,
+        "queue_set_reject_self_audits" => interact.queue_set_reject_self_audits().await,
+        "isRejectingSelfAudits" => interact.reject_self_audits().await,
+        "set_contract_category" => interact.set_contract_category().await,
+        "add_contract_tags" => interact.add_contract_tags().await,
+        "remove_contract_tags" => interact.remove_contract_tags().await,
+        "getContractCategory" => interact.get_contract_category().await,
+        "getContractTags" => interact.get_contract_tags().await,
+        "getContractsByTag" => interact.get_contracts_by_tag().await,
+        "getVisibleContractsByTag" => interact.get_visible_contracts_by_tag().await,
+        "getTotalContractsByTag" => interact.get_total_contracts_by_tag().await,
+        "getContractsByCategory" => interact.get_contracts_by_category().await,
+        "getVisibleContractsByCategory" => interact.get_visible_contracts_by_category().await,
+        "getTotalContractsByCategory" => interact.get_total_contracts_by_category().await,
+        "getAuditsBetween" => interact.get_audits_between().await,
+        "getContractsByMinStars" => interact.get_contracts_by_min_stars().await,
+        "getTotalContractsByStars" => interact.get_total_contracts_by_stars().await,
+        "amend_audit" => interact.amend_audit().await,
+        "revoke_audit" => interact.revoke_audit().await,
+        "getRecentAudits" => interact.get_recent_audits().await,
+        "getTotalAuditLogEntries" => interact.get_total_audit_log_entries().await,
+        "watch" => interact.watch().await,
+        "unwatch" => interact.unwatch().await,
+        "getWatchers" => interact.get_watchers().await,
+        "getTotalWatchers" => interact.get_total_watchers().await,
+        "getWatchedContracts" => interact.get_watched_contracts().await,
+        "queue_set_coverage_terms" => interact.queue_set_coverage_terms().await,
+        "queue_add_arbiter" => interact.queue_add_arbiter().await,
+        "queue_remove_arbiter" => interact.queue_remove_arbiter().await,
+        "deposit_coverage_capital" => interact.deposit_coverage_capital().await,
+        "withdraw_coverage_capital" => interact.withdraw_coverage_capital().await,
+        "buy_coverage" => interact.buy_coverage().await,
+        "file_coverage_claim" => interact.file_coverage_claim().await,
+        "vote_on_claim" => interact.vote_on_claim().await,
+        "release_coverage" => interact.release_coverage().await,
+        "expire_coverage_claim" => interact.expire_coverage_claim().await,
+        "getCoverageTerms" => interact.get_coverage_terms().await,
+        "quotePremium" => interact.quote_premium().await,
+        "getCoveragePool" => interact.get_coverage_pool().await,
+        "getUnderwriterPosition" => interact.get_underwriter_position().await,
+        "getCoveragePolicy" => interact.get_coverage_policy().await,
+        "getCoverageClaim" => interact.get_coverage_claim().await,
+        "getContractPolicies" => interact.get_contract_policies().await,
+        "getArbiters" => interact.arbiters().await,
+        "propose_team_audit" => interact.propose_team_audit().await,
+        "sign_team_audit" => interact.sign_team_audit().await,
+        "cancel_team_audit" => interact.cancel_team_audit().await,
+        "getTeamAuditDraft" => interact.get_team_audit_draft().await,
+        "getTeamAuditSignatures" => interact.get_team_audit_signatures().await,
+        "getAuditSigners" => interact.get_audit_signers().await,
+        "commit_audit" => interact.commit_audit().await,
+        "publish_audit" => interact.publish_audit().await,
+        "getAuditCommitment" => interact.get_audit_commitment().await,
+        "getPendingCommitments" => interact.get_pending_commitments().await,
+        "getPublishedCommitment" => interact.get_published_commitment().await,
+        "getBountyPoolReports" => interact.get_bounty_pool_reports().await,
         _ => panic!("unknown command: {}", &cmd),
     }
 }
@@ -40,6 +226,63 @@ pub struct State {
     contract_address: Option<Bech32Address>
 }
 
+// Decodes a hex encoded 32-byte contract hash, with or without the 0x prefix
+fn parse_contract_hash(hash_hex: &str) -> [u8; 32] {
+    hex::decode(hash_hex.trim_start_matches("0x"))
+        .expect("contract hash must be hex encoded")
+        .try_into()
+        .expect("contract hash must be 32 bytes long")
+}
+
+// Hashes a commit-reveal audit the way publish_audit does, without sending the salt anywhere
+pub fn compute_audit_commitment(
+    auditor_bech32: &str,
+    contract_hash_hex: &str,
+    stars: u8,
+    summary: &str,
+    salt_hex: &str,
+) -> [u8; 32] {
+    let salt: [u8; 32] = hex::decode(salt_hex.trim_start_matches("0x"))
+        .expect("salt must be hex encoded")
+        .try_into()
+        .expect("salt must be 32 bytes long");
+    let reveal = AuditReveal::<StaticApi> {
+        auditor: Bech32Address::from_bech32_string(auditor_bech32.to_string()).to_address().into(),
+        contract_hash: ManagedByteArray::new_from_bytes(&parse_contract_hash(contract_hash_hex)),
+        stars,
+        summary: ManagedBuffer::from(summary),
+        salt: ManagedByteArray::new_from_bytes(&salt),
+    };
+
+    let mut encoded = ManagedBuffer::<StaticApi>::new();
+    reveal.top_encode(&mut encoded).expect("reveal must encode");
+    keccak256(&encoded.to_boxed_bytes().into_vec())
+}
+
+/// A single entry of a batch registration file
+#[derive(Debug, Deserialize)]
+pub struct BatchAuditEntry {
+    contract_hash: String,
+    stars: u8,
+    summary: String,
+}
+
+/// Batch registration file, listing audits under `[[audits]]`
+#[derive(Debug, Deserialize)]
+pub struct AuditBatch {
+    audits: Vec<BatchAuditEntry>,
+}
+
+impl AuditBatch {
+    // Deserializes a batch from file
+    pub fn load(path: &str) -> Self {
+        let mut file = std::fs::File::open(path).unwrap();
+        let mut content = String::new();
+        file.read_to_string(&mut content).unwrap();
+        toml::from_str(&content).unwrap()
+    }
+}
+
 impl State {
         // Deserializes state from file
         pub fn load_state() -> Self {
@@ -148,16 +391,80 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
+    pub async fn register_audits_batch(&mut self, batch_file: &str) {
+        let batch = AuditBatch::load(batch_file);
+        let registration_fee = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .registration_fee()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        for chunk in batch.audits.chunks(MAX_BATCH_SIZE) {
+            let mut audits = MultiValueEncoded::new();
+            for entry in chunk {
+                let hash_bytes = parse_contract_hash(&entry.contract_hash);
+                let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+                let summary = ManagedBuffer::new_from_bytes(entry.summary.as_bytes());
+
+                audits.push(MultiValue3::from((contract_hash, entry.stars, summary)));
+            }
+
+            let response = self
+                .interactor
+                .tx()
+                .from(&self.wallet_address)
+                .to(self.state.current_address())
+                .gas(10_000_000u64 + 5_000_000u64 * chunk.len() as u64)
+                .typed(proxy::AuditRegistryProxy)
+                .register_audits_batch(audits)
+                .egld(BigUint::<StaticApi>::from(&registration_fee * chunk.len()))
+                .returns(ReturnsResultUnmanaged)
+                .run()
+                .await;
+
+            println!("Registered {} audits, result: {response:?}", chunk.len());
+        }
+    }
+
+    // Moves audits left in the pre-header contractAudits layout, one batch of hashes per transaction
+    pub async fn migrate_legacy_audits(&mut self, contract_hashes: &[String]) {
+        for chunk in contract_hashes.chunks(MAX_BATCH_SIZE) {
+            let mut hashes = MultiValueEncoded::new();
+            for hash_hex in chunk {
+                hashes.push(ManagedByteArray::new_from_bytes(&parse_contract_hash(hash_hex)));
+            }
+
+            let response = self
+                .interactor
+                .tx()
+                .from(&self.wallet_address)
+                .to(self.state.current_address())
+                .gas(10_000_000u64 + 20_000_000u64 * chunk.len() as u64)
+                .typed(proxy::AuditRegistryProxy)
+                .migrate_legacy_audits(hashes)
+                .returns(ReturnsResultUnmanaged)
+                .run()
+                .await;
+
+            println!("Migrated {} contracts, result: {response:?}", chunk.len());
+        }
+    }
+
     pub async fn get_all_audits(&mut self) {
         let start_index = 0u32;
         let limit = 0u32;
+        let kind = OptionalValue::<proxy::AuditKind>::None;
 
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::AuditRegistryProxy)
-            .get_all_audits(start_index, limit)
+            .get_all_audits(start_index, limit, kind)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -182,13 +489,14 @@ impl ContractInteract {
     pub async fn get_contract_audits(&mut self) {
         let hash_bytes = [0u8; 32]; 
         let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let kind = OptionalValue::<proxy::AuditKind>::None;
 
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::AuditRegistryProxy)
-            .get_contract_audits(contract_hash)
+            .get_contract_audits(contract_hash, kind)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -229,7 +537,51 @@ impl ContractInteract {
         println!("Result: {result_value:?}");
     }
 
-    pub async fn withdraw(&mut self) {
+    pub async fn queue_withdraw(&mut self) {
+        self.queue_action(proxy::AdminAction::Withdraw).await;
+    }
+
+    pub async fn owner(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .owner()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn locked_funds(&mut self) {
+        let token = EgldOrEsdtTokenIdentifier::egld();
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .locked_funds(token)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn create_bounty(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let duration = 0u64;
+        let low_reward = BigUint::<StaticApi>::from(0u128);
+        let medium_reward = BigUint::<StaticApi>::from(0u128);
+        let high_reward = BigUint::<StaticApi>::from(0u128);
+        let critical_reward = BigUint::<StaticApi>::from(0u128);
+
         let response = self
             .interactor
             .tx()
@@ -237,7 +589,8 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::AuditRegistryProxy)
-            .withdraw()
+            .create_bounty(contract_hash, duration, low_reward, medium_reward, high_reward, critical_reward)
+            .egld(egld_amount)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -245,13 +598,2489 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
-    pub async fn owner(&mut self) {
+    pub async fn fund_bounty(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .fund_bounty(contract_hash)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn submit_vulnerability_report(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let report_hash_bytes = [0u8; 32]; 
+        let report_hash = ManagedByteArray::new_from_bytes(&report_hash_bytes);
+        let severity = proxy::Severity::Low;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .submit_vulnerability_report(contract_hash, report_hash, severity)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn approve_report(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let report_index = 1u32;
+        let severity = proxy::Severity::Low;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .approve_report(contract_hash, report_index, severity)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn reject_report(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let report_index = 1u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .reject_report(contract_hash, report_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn close_bounty(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .close_bounty(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_bounty_pool(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::AuditRegistryProxy)
-            .owner()
+            .get_bounty_pool(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_bounty_reports(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_bounty_reports(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn bounty_contracts(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .bounty_contracts()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn register_chain_audit(&mut self) {
+        let chain_id = ManagedBuffer::new_from_bytes(&b"multiversx"[..]);
+        let hash_algorithm = proxy::HashAlgorithm::MultiversXCodeHash;
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let stars = 0u8;
+        let summary = ManagedBuffer::new_from_bytes(&b""[..]);
+        let provenance = OptionalValue::Some(proxy::AuditProvenance {
+            kind: proxy::AuditKind::Ai,
+            model_id: ManagedBuffer::new_from_bytes(&b""[..]),
+            model_version: ManagedBuffer::new_from_bytes(&b""[..]),
+            prompt_template_hash: ManagedBuffer::new(),
+            tool_version: ManagedBuffer::new_from_bytes(&b""[..]),
+        });
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .register_chain_audit(chain_id, hash_algorithm, contract_hash, stars, summary, provenance)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_all_audits_by_chain(&mut self) {
+        let chain_id = ManagedBuffer::new_from_bytes(&b"multiversx"[..]);
+        let start_index = 0u32;
+        let limit = 0u32;
+        let kind = OptionalValue::<proxy::AuditKind>::None;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_all_audits_by_chain(chain_id, start_index, limit, kind)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_total_contracts_by_chain(&mut self) {
+        let chain_id = ManagedBuffer::new_from_bytes(&b"multiversx"[..]);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_total_contracts_by_chain(chain_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_contract_key(&mut self) {
+        let chain_id = ManagedBuffer::new_from_bytes(&b"multiversx"[..]);
+        let hash_algorithm = proxy::HashAlgorithm::MultiversXCodeHash;
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_contract_key(chain_id, hash_algorithm, contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_contract_namespace(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_key = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_contract_namespace(contract_key)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn known_chains(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .known_chains()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_audit_scope(
+        &mut self,
+        checkout_dir: &str,
+        contract_hash: &str,
+        audit_index: usize,
+        pathspecs: &[String],
+    ) {
+        let scope = GitScope::from_checkout(checkout_dir, pathspecs);
+        println!("Scope: {scope:?}");
+
+        let contract_hash = ManagedByteArray::new_from_bytes(&parse_contract_hash(contract_hash));
+        let repository_url = ManagedBuffer::new_from_bytes(scope.repository_url.as_bytes());
+        let commit_hash = ManagedBuffer::new_from_bytes(&scope.commit_hash);
+        let compiler_version = ManagedBuffer::new_from_bytes(scope.compiler_version.as_bytes());
+        let mut files = MultiValueEncoded::new();
+        for (path, file_hash) in &scope.files {
+            files.push(MultiValue2::from((
+                ManagedBuffer::new_from_bytes(path.as_bytes()),
+                ManagedByteArray::new_from_bytes(file_hash),
+            )));
+        }
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(50_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .set_audit_scope(contract_hash, audit_index, repository_url, commit_hash, compiler_version, files)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_audit_scope(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_audit_scope(contract_hash, audit_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn add_moderator(&mut self) {
+        let moderator = bech32::decode("");
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .add_moderator(moderator)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn remove_moderator(&mut self) {
+        let moderator = bech32::decode("");
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .remove_moderator(moderator)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn hide_audit(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+        let reason = proxy::ModerationReason::Spam;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .hide_audit(contract_hash, audit_index, reason)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn unhide_audit(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+        let reason = proxy::ModerationReason::AppealAccepted;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .unhide_audit(contract_hash, audit_index, reason)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn hide_contract(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let reason = proxy::ModerationReason::Spam;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .hide_contract(contract_hash, reason)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn unhide_contract(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let reason = proxy::ModerationReason::AppealAccepted;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .unhide_contract(contract_hash, reason)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn block_auditor(&mut self) {
+        let auditor = bech32::decode("");
+        let reason = proxy::ModerationReason::Spam;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .block_auditor(auditor, reason)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn unblock_auditor(&mut self) {
+        let auditor = bech32::decode("");
+        let reason = proxy::ModerationReason::AppealAccepted;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .unblock_auditor(auditor, reason)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn is_audit_hidden(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .is_audit_hidden(contract_hash, audit_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn hidden_contract(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .hidden_contract(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_moderation_log_length(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_moderation_log_length()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_moderation_log(&mut self) {
+        let start_index = 0u32;
+        let limit = 0u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_moderation_log(start_index, limit)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn moderators(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .moderators()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn blocked_auditors(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .blocked_auditors()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_visible_audits(&mut self) {
+        let start_index = 0u32;
+        let limit = 0u32;
+        let kind = OptionalValue::<proxy::AuditKind>::None;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_visible_audits(start_index, limit, kind)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_visible_audits_by_chain(&mut self) {
+        let chain_id = ManagedBuffer::new_from_bytes(&b"multiversx"[..]);
+        let start_index = 0u32;
+        let limit = 0u32;
+        let kind = OptionalValue::<proxy::AuditKind>::None;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_visible_audits_by_chain(chain_id, start_index, limit, kind)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_visible_contract_audits(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let kind = OptionalValue::<proxy::AuditKind>::None;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_visible_contract_audits(contract_hash, kind)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_visible_auditor_history(&mut self) {
+        let auditor = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_visible_auditor_history(auditor)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_latest_visible_audit(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_latest_visible_audit(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn queue_set_rate_limits(&mut self) {
+        let limits = proxy::RateLimits {
+            max_audits_per_window: 10u32,
+            window_duration: 86_400u64,
+            same_contract_cooldown: 3_600u64,
+        };
+
+        self.queue_action(proxy::AdminAction::SetRateLimits(limits)).await;
+    }
+
+    pub async fn get_remaining_quota(&mut self) {
+        let auditor = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_remaining_quota(auditor)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_next_allowed_audit_time(&mut self) {
+        let auditor = bech32::decode("");
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_next_allowed_audit_time(auditor, contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_rate_limits(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_rate_limits()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn review_audit(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+        let verdict = proxy::ReviewVerdict::Endorse;
+        let comment = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .review_audit(contract_hash, audit_index, verdict, comment)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_audit_reviews(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_audit_reviews(contract_hash, audit_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_auditor_reputation(&mut self) {
+        let auditor = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_auditor_reputation(auditor)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_governance_token(&mut self) {
+        let token_id = TokenIdentifier::from_esdt_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .set_governance_token(token_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn queue_set_voting_period(&mut self) {
+        let voting_period = 604_800u64;
+
+        self.queue_action(proxy::AdminAction::SetVotingPeriod(voting_period)).await;
+    }
+
+    pub async fn vote_on_audit(&mut self) {
+        let token_id = String::new();
+        let token_nonce = 0u64;
+        let token_amount = BigUint::<StaticApi>::from(0u128);
+
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+        let upvote = true;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .vote_on_audit(contract_hash, audit_index, upvote)
+            .payment((TokenIdentifier::from(token_id.as_str()), token_nonce, token_amount))
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn unlock_votes(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .unlock_votes(contract_hash, audit_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_voting_window_end(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_voting_window_end(contract_hash, audit_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn governance_token(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .governance_token()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn voting_period(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .voting_period()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_audit_votes(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_audit_votes(contract_hash, audit_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_vote_lock(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+        let voter = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_vote_lock(contract_hash, audit_index, voter)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn propose(&mut self) {
+        let action = proxy::ProposalAction::SetMaxSummaryLength(500);
+        let description = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .propose(action, description)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn vote_proposal(&mut self) {
+        let proposal_id = 1u32;
+        let approve = true;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .vote_proposal(proposal_id, approve)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn execute_proposal(&mut self) {
+        let proposal_id = 1u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .execute_proposal(proposal_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn veto_proposal(&mut self) {
+        let proposal_id = 1u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .veto_proposal(proposal_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_proposal(&mut self) {
+        let proposal_id = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_proposal(proposal_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_proposal_count(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_proposal_count()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_proposal_status(&mut self) {
+        let proposal_id = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_proposal_status(proposal_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn has_voted_on_proposal(&mut self) {
+        let proposal_id = 1u32;
+        let voter = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .has_voted_on_proposal(proposal_id, voter)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn registration_fee(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .registration_fee()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn audit_validity_period(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .audit_validity_period()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn admission_required(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .admission_required()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn admitted_auditors(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .admitted_auditors()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_max_summary_length(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_max_summary_length()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn is_audit_valid(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .is_audit_valid(contract_hash, audit_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    // Owner actions go through the timelock: queue them here, then run
+    // execute_admin_action with the printed id once the delay has passed
+    async fn queue_action(&mut self, action: proxy::AdminAction<StaticApi>) {
+        let action_id = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .queue_admin_action(action)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Queued admin action {action_id}; execute it with execute_admin_action after the timelock delay");
+    }
+
+    pub async fn queue_admin_action(&mut self) {
+        let action = proxy::AdminAction::<StaticApi>::SetTimelockDelay(172_800u64);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .queue_admin_action(action)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn cancel_admin_action(&mut self) {
+        let action_id = 1u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .cancel_admin_action(action_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn execute_admin_action(&mut self) {
+        let action_id = 1u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .execute_admin_action(action_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_admin_action(&mut self) {
+        let action_id = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_admin_action(action_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_queued_actions(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_queued_actions()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_timelock_delay(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_timelock_delay()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn approved_upgrade(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .approved_upgrade()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn claim_rewards(&mut self) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .claim_rewards()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_claimable_rewards(&mut self) {
+        let auditor = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_claimable_rewards(auditor)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_total_claimable_rewards(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_total_claimable_rewards()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn auditor_share_bps(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .auditor_share_bps()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn link_project(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let sc_address = bech32::decode("");
+        let project_owner = bech32::decode("");
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .link_project(contract_hash, sc_address, project_owner)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn unlink_project(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .unlink_project(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn respond_to_audit(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+        let status = proxy::ResponseStatus::Acknowledged;
+        let comment = ManagedBuffer::new_from_bytes(&b""[..]);
+        let fixed_code_hash = OptionalValue::<ManagedByteArray<StaticApi, 32>>::None;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .respond_to_audit(contract_hash, audit_index, status, comment, fixed_code_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_project_link(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_project_link(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_audit_response(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_audit_response(contract_hash, audit_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_remediation_audits(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_remediation_audits(contract_hash, audit_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_visible_remediation_audits(&mut self) {
+        let hash_bytes = [0u8; 32];
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_visible_remediation_audits(contract_hash, audit_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn record_upgrade(&mut self) {
+        let sc_address = bech32::decode("");
+        let hash_bytes = [0u8; 32]; 
+        let new_code_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .record_upgrade(sc_address, new_code_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_contract_lineage(&mut self) {
+        let sc_address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_contract_lineage(sc_address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_lineage_audits(&mut self) {
+        let sc_address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_lineage_audits(sc_address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_visible_lineage_audits(&mut self) {
+        let sc_address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_visible_lineage_audits(sc_address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn queue_set_reject_self_audits(&mut self) {
+        let reject = true;
+
+        self.queue_action(proxy::AdminAction::SetRejectSelfAudits(reject)).await;
+    }
+
+    pub async fn reject_self_audits(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .reject_self_audits()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_contract_category(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let category = proxy::ContractCategory::DeFi;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .set_contract_category(contract_hash, category)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn add_contract_tags(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let mut tags = MultiValueEncoded::new();
+        tags.push(ManagedBuffer::new_from_bytes(&b"defi"[..]));
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .add_contract_tags(contract_hash, tags)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn remove_contract_tags(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let mut tags = MultiValueEncoded::new();
+        tags.push(ManagedBuffer::new_from_bytes(&b"defi"[..]));
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .remove_contract_tags(contract_hash, tags)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_contract_category(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_contract_category(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_contract_tags(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_contract_tags(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_contracts_by_tag(&mut self) {
+        let tag = ManagedBuffer::new_from_bytes(&b"defi"[..]);
+        let start_index = 0u32;
+        let limit = 10u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_contracts_by_tag(tag, start_index, limit)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_visible_contracts_by_tag(&mut self) {
+        let tag = ManagedBuffer::new_from_bytes(&b"defi"[..]);
+        let start_index = 0u32;
+        let limit = 10u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_visible_contracts_by_tag(tag, start_index, limit)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_total_contracts_by_tag(&mut self) {
+        let tag = ManagedBuffer::new_from_bytes(&b"defi"[..]);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_total_contracts_by_tag(tag)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_contracts_by_category(&mut self) {
+        let category = proxy::ContractCategory::DeFi;
+        let start_index = 0u32;
+        let limit = 10u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_contracts_by_category(category, start_index, limit)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_visible_contracts_by_category(&mut self) {
+        let category = proxy::ContractCategory::DeFi;
+        let start_index = 0u32;
+        let limit = 10u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_visible_contracts_by_category(category, start_index, limit)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_total_contracts_by_category(&mut self) {
+        let category = proxy::ContractCategory::DeFi;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_total_contracts_by_category(category)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_audits_between(&mut self) {
+        let from_timestamp = 0u64;
+        let to_timestamp = 0u64;
+        let start_index = 0u32;
+        let limit = 10u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_audits_between(from_timestamp, to_timestamp, start_index, limit)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_contracts_by_min_stars(&mut self) {
+        let min_stars = 4u8;
+        let start_index = 0u32;
+        let limit = 10u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_contracts_by_min_stars(min_stars, start_index, limit)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_total_contracts_by_stars(&mut self) {
+        let stars = 5u8;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_total_contracts_by_stars(stars)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn amend_audit(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+        let stars = 5u8;
+        let summary = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .amend_audit(contract_hash, audit_index, stars, summary)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn revoke_audit(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .revoke_audit(contract_hash, audit_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_recent_audits(&mut self) {
+        let start_index = 0u32;
+        let limit = 10u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_recent_audits(start_index, limit)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_total_audit_log_entries(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_total_audit_log_entries()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn watch(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .watch(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn unwatch(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .unwatch(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_watchers(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let start_index = 0u32;
+        let limit = 10u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_watchers(contract_hash, start_index, limit)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_total_watchers(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_total_watchers(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_watched_contracts(&mut self) {
+        let watcher = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_watched_contracts(watcher)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn queue_set_coverage_terms(&mut self) {
+        let terms = proxy::CoverageTerms {
+            min_stars: 4u8,
+            premium_bps: 500u32,
+            protocol_fee_bps: 1_000u32,
+            claim_quorum: 2usize,
+        };
+
+        self.queue_action(proxy::AdminAction::SetCoverageTerms(terms)).await;
+    }
+
+    pub async fn queue_add_arbiter(&mut self) {
+        let arbiter = bech32::decode("");
+
+        self.queue_action(proxy::AdminAction::AddArbiter(arbiter.into())).await;
+    }
+
+    pub async fn queue_remove_arbiter(&mut self) {
+        let arbiter = bech32::decode("");
+
+        self.queue_action(proxy::AdminAction::RemoveArbiter(arbiter.into())).await;
+    }
+
+    pub async fn deposit_coverage_capital(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .deposit_coverage_capital()
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn withdraw_coverage_capital(&mut self) {
+        let shares = BigUint::<StaticApi>::from(0u128);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .withdraw_coverage_capital(shares)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn buy_coverage(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let covered_amount = BigUint::<StaticApi>::from(0u128);
+        let period = 2_592_000u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .buy_coverage(contract_hash, covered_amount, period)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn file_coverage_claim(&mut self) {
+        let policy_id = 1u32;
+        let evidence_bytes = [0u8; 32]; 
+        let evidence_hash = ManagedByteArray::new_from_bytes(&evidence_bytes);
+        let exploited_at = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .file_coverage_claim(policy_id, evidence_hash, exploited_at)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn vote_on_claim(&mut self) {
+        let policy_id = 1u32;
+        let approve = true;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .vote_on_claim(policy_id, approve)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn release_coverage(&mut self) {
+        let policy_id = 1u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .release_coverage(policy_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn expire_coverage_claim(&mut self) {
+        let policy_id = 1u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .expire_coverage_claim(policy_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_coverage_terms(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_coverage_terms()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn quote_premium(&mut self) {
+        let covered_amount = BigUint::<StaticApi>::from(0u128);
+        let period = 2_592_000u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .quote_premium(covered_amount, period)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_coverage_pool(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_coverage_pool()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_underwriter_position(&mut self) {
+        let underwriter = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_underwriter_position(underwriter)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_coverage_policy(&mut self) {
+        let policy_id = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_coverage_policy(policy_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_coverage_claim(&mut self) {
+        let policy_id = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_coverage_claim(policy_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_contract_policies(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_contract_policies(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn arbiters(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .arbiters()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn propose_team_audit(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let stars = 0u8;
+        let summary = ManagedBuffer::new_from_bytes(&b""[..]);
+        let mut co_auditors = MultiValueEncoded::new();
+        co_auditors.push(ManagedAddress::<StaticApi>::from(bech32::decode("").as_array()));
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .propose_team_audit(contract_hash, stars, summary, co_auditors)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn sign_team_audit(&mut self) {
+        let draft_id = 1u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .sign_team_audit(draft_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn cancel_team_audit(&mut self) {
+        let draft_id = 1u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .cancel_team_audit(draft_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_team_audit_draft(&mut self) {
+        let draft_id = 1u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_team_audit_draft(draft_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_team_audit_signatures(&mut self) {
+        let draft_id = 1u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_team_audit_signatures(draft_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_audit_signers(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_audit_signers(contract_hash, audit_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn commit_audit(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let commitment_bytes = [0u8; 32]; 
+        let commitment = ManagedByteArray::new_from_bytes(&commitment_bytes);
+        let disclosure_time = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .commit_audit(contract_hash, commitment, disclosure_time)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn publish_audit(&mut self) {
+        let commitment_id = 1u64;
+        let stars = 0u8;
+        let summary = ManagedBuffer::new_from_bytes(&b""[..]);
+        let salt_bytes = [0u8; 32]; 
+        let salt = ManagedByteArray::new_from_bytes(&salt_bytes);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::AuditRegistryProxy)
+            .publish_audit(commitment_id, stars, summary, salt)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_audit_commitment(&mut self) {
+        let commitment_id = 1u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_audit_commitment(commitment_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_pending_commitments(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_pending_commitments(contract_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_published_commitment(&mut self) {
+        let hash_bytes = [0u8; 32]; 
+        let contract_hash = ManagedByteArray::new_from_bytes(&hash_bytes);
+        let audit_index = 1u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_published_commitment(contract_hash, audit_index)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_bounty_pool_reports(&mut self) {
+        let pool_id = 1u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::AuditRegistryProxy)
+            .get_bounty_pool_reports(pool_id)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -53,6 +53,25 @@ where
     }
 }
 
+#[rustfmt::skip]
+impl<Env, From, To, Gas> AuditRegistryProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
 #[rustfmt::skip]
 impl<Env, From, To, Gas> AuditRegistryProxyMethods<Env, From, To, Gas>
 where
@@ -71,29 +90,227 @@ where
         contract_hash: Arg0,
         stars: Arg1,
         summary: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("register_audit")
+            .argument(&contract_hash)
+            .argument(&stars)
+            .argument(&summary)
+            .original_result()
+    }
+
+    pub fn register_chain_audit<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<HashAlgorithm>,
+        Arg2: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg3: ProxyArg<u8>,
+        Arg4: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg5: ProxyArg<OptionalValue<AuditProvenance<Env::Api>>>,
+    >(
+        self,
+        chain_id: Arg0,
+        hash_algorithm: Arg1,
+        contract_hash: Arg2,
+        stars: Arg3,
+        summary: Arg4,
+        provenance: Arg5,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .raw_call("register_chain_audit")
+            .argument(&chain_id)
+            .argument(&hash_algorithm)
+            .argument(&contract_hash)
+            .argument(&stars)
+            .argument(&summary)
+            .argument(&provenance)
+            .original_result()
+    }
+
+    pub fn register_audits_batch<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<ManagedByteArray<Env::Api, 32usize>, u8, ManagedBuffer<Env::Api>>>>,
+    >(
+        self,
+        audits: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("register_audits_batch")
+            .argument(&audits)
+            .original_result()
+    }
+
+    pub fn migrate_legacy_audits<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedByteArray<Env::Api, 32usize>>>,
+    >(
+        self,
+        contract_hashes: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("register_audit")
+            .raw_call("migrate_legacy_audits")
+            .argument(&contract_hashes)
+            .original_result()
+    }
+
+    pub fn amend_audit<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<u8>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+        stars: Arg2,
+        summary: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("amend_audit")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .argument(&stars)
+            .argument(&summary)
+            .original_result()
+    }
+
+    pub fn revoke_audit<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revoke_audit")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .original_result()
+    }
+
+    pub fn propose_team_audit<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<u8>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        contract_hash: Arg0,
+        stars: Arg1,
+        summary: Arg2,
+        co_auditors: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("propose_team_audit")
+            .argument(&contract_hash)
+            .argument(&stars)
+            .argument(&summary)
+            .argument(&co_auditors)
+            .original_result()
+    }
+
+    pub fn sign_team_audit<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        draft_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("sign_team_audit")
+            .argument(&draft_id)
+            .original_result()
+    }
+
+    pub fn cancel_team_audit<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        draft_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancel_team_audit")
+            .argument(&draft_id)
+            .original_result()
+    }
+
+    pub fn commit_audit<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        contract_hash: Arg0,
+        commitment: Arg1,
+        disclosure_time: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("commit_audit")
             .argument(&contract_hash)
+            .argument(&commitment)
+            .argument(&disclosure_time)
+            .original_result()
+    }
+
+    pub fn publish_audit<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u8>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        commitment_id: Arg0,
+        stars: Arg1,
+        summary: Arg2,
+        salt: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("publish_audit")
+            .argument(&commitment_id)
             .argument(&stars)
             .argument(&summary)
+            .argument(&salt)
             .original_result()
     }
 
     pub fn get_all_audits<
         Arg0: ProxyArg<usize>,
         Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<OptionalValue<AuditKind>>,
     >(
         self,
         start_index: Arg0,
         limit: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue5<ManagedByteArray<Env::Api, 32usize>, u8, ManagedBuffer<Env::Api>, ManagedAddress<Env::Api>, u64>>> {
+        kind: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue6<ManagedByteArray<Env::Api, 32usize>, u8, ManagedBuffer<Env::Api>, ManagedAddress<Env::Api>, u64, bool>>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("get_all_audits")
             .argument(&start_index)
             .argument(&limit)
+            .argument(&kind)
+            .original_result()
+    }
+
+    pub fn get_visible_audits<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<OptionalValue<AuditKind>>,
+    >(
+        self,
+        start_index: Arg0,
+        limit: Arg1,
+        kind: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue6<ManagedByteArray<Env::Api, 32usize>, u8, ManagedBuffer<Env::Api>, ManagedAddress<Env::Api>, u64, bool>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVisibleAudits")
+            .argument(&start_index)
+            .argument(&limit)
+            .argument(&kind)
             .original_result()
     }
 
@@ -106,16 +323,124 @@ where
             .original_result()
     }
 
+    pub fn get_all_audits_by_chain<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<OptionalValue<AuditKind>>,
+    >(
+        self,
+        chain_id: Arg0,
+        start_index: Arg1,
+        limit: Arg2,
+        kind: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue6<ManagedByteArray<Env::Api, 32usize>, u8, ManagedBuffer<Env::Api>, ManagedAddress<Env::Api>, u64, bool>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllAuditsByChain")
+            .argument(&chain_id)
+            .argument(&start_index)
+            .argument(&limit)
+            .argument(&kind)
+            .original_result()
+    }
+
+    pub fn get_visible_audits_by_chain<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<OptionalValue<AuditKind>>,
+    >(
+        self,
+        chain_id: Arg0,
+        start_index: Arg1,
+        limit: Arg2,
+        kind: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue6<ManagedByteArray<Env::Api, 32usize>, u8, ManagedBuffer<Env::Api>, ManagedAddress<Env::Api>, u64, bool>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVisibleAuditsByChain")
+            .argument(&chain_id)
+            .argument(&start_index)
+            .argument(&limit)
+            .argument(&kind)
+            .original_result()
+    }
+
+    pub fn get_total_contracts_by_chain<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        chain_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalContractsByChain")
+            .argument(&chain_id)
+            .original_result()
+    }
+
+    pub fn get_contract_key<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<HashAlgorithm>,
+        Arg2: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        chain_id: Arg0,
+        hash_algorithm: Arg1,
+        contract_hash: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractKey")
+            .argument(&chain_id)
+            .argument(&hash_algorithm)
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn get_contract_namespace<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_key: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ContractNamespace<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractNamespace")
+            .argument(&contract_key)
+            .original_result()
+    }
+
     pub fn get_contract_audits<
         Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<OptionalValue<AuditKind>>,
     >(
         self,
         contract_hash: Arg0,
+        kind: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Audit<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("getContractAudits")
             .argument(&contract_hash)
+            .argument(&kind)
+            .original_result()
+    }
+
+    pub fn get_visible_contract_audits<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<OptionalValue<AuditKind>>,
+    >(
+        self,
+        contract_hash: Arg0,
+        kind: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Audit<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVisibleContractAudits")
+            .argument(&contract_hash)
+            .argument(&kind)
             .original_result()
     }
 
@@ -132,6 +457,19 @@ where
             .original_result()
     }
 
+    pub fn get_visible_auditor_history<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        auditor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedByteArray<Env::Api, 32usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVisibleAuditorHistory")
+            .argument(&auditor)
+            .original_result()
+    }
+
     pub fn get_latest_audit<
         Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
     >(
@@ -145,12 +483,32 @@ where
             .original_result()
     }
 
-    pub fn withdraw(
+    pub fn get_latest_visible_audit<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Audit<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLatestVisibleAudit")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn is_audit_valid<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("withdraw")
+            .raw_call("isAuditValid")
+            .argument(&contract_hash)
+            .argument(&audit_index)
             .original_result()
     }
 
@@ -162,16 +520,2153 @@ where
             .raw_call("getOwner")
             .original_result()
     }
-}
 
-#[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Debug)]
-pub struct Audit<Api>
-where
-    Api: ManagedTypeApi,
-{
-    pub stars: u8,
-    pub summary: ManagedBuffer<Api>,
-    pub auditor: ManagedAddress<Api>,
-    pub timestamp: u64,
+    pub fn known_chains(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getKnownChains")
+            .original_result()
+    }
+
+    pub fn hidden_contract<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isContractHidden")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn blocked_auditors(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBlockedAuditors")
+            .original_result()
+    }
+
+    pub fn registration_fee(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRegistrationFee")
+            .original_result()
+    }
+
+    pub fn audit_validity_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAuditValidityPeriod")
+            .original_result()
+    }
+
+    pub fn admission_required(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAdmissionRequired")
+            .original_result()
+    }
+
+    pub fn admitted_auditors(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAdmittedAuditors")
+            .original_result()
+    }
+
+    pub fn get_max_summary_length(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxSummaryLength")
+            .original_result()
+    }
+
+    pub fn locked_funds<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLockedFunds")
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn create_bounty<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+        Arg5: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        contract_hash: Arg0,
+        duration: Arg1,
+        low_reward: Arg2,
+        medium_reward: Arg3,
+        high_reward: Arg4,
+        critical_reward: Arg5,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("create_bounty")
+            .argument(&contract_hash)
+            .argument(&duration)
+            .argument(&low_reward)
+            .argument(&medium_reward)
+            .argument(&high_reward)
+            .argument(&critical_reward)
+            .original_result()
+    }
+
+    pub fn fund_bounty<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fund_bounty")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn submit_vulnerability_report<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg2: ProxyArg<Severity>,
+    >(
+        self,
+        contract_hash: Arg0,
+        report_hash: Arg1,
+        severity: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("submit_vulnerability_report")
+            .argument(&contract_hash)
+            .argument(&report_hash)
+            .argument(&severity)
+            .original_result()
+    }
+
+    pub fn approve_report<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<Severity>,
+    >(
+        self,
+        contract_hash: Arg0,
+        report_index: Arg1,
+        severity: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approve_report")
+            .argument(&contract_hash)
+            .argument(&report_index)
+            .argument(&severity)
+            .original_result()
+    }
+
+    pub fn reject_report<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        report_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("reject_report")
+            .argument(&contract_hash)
+            .argument(&report_index)
+            .original_result()
+    }
+
+    pub fn close_bounty<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("close_bounty")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn get_bounty_pool<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<BountyPool<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBountyPool")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn get_bounty_reports<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, VulnerabilityReport<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBountyReports")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn get_bounty_pool_reports<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        pool_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, VulnerabilityReport<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBountyPoolReports")
+            .argument(&pool_id)
+            .original_result()
+    }
+
+    pub fn bounty_contracts(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedByteArray<Env::Api, 32usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActiveBounties")
+            .original_result()
+    }
+
+    pub fn set_audit_scope<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg5: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedBuffer<Env::Api>, ManagedByteArray<Env::Api, 32usize>>>>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+        repository_url: Arg2,
+        commit_hash: Arg3,
+        compiler_version: Arg4,
+        files: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_audit_scope")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .argument(&repository_url)
+            .argument(&commit_hash)
+            .argument(&compiler_version)
+            .argument(&files)
+            .original_result()
+    }
+
+    pub fn get_audit_scope<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<AuditScope<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAuditScope")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .original_result()
+    }
+
+    pub fn add_moderator<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        moderator: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("add_moderator")
+            .argument(&moderator)
+            .original_result()
+    }
+
+    pub fn remove_moderator<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        moderator: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("remove_moderator")
+            .argument(&moderator)
+            .original_result()
+    }
+
+    pub fn hide_audit<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ModerationReason>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+        reason: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hide_audit")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .argument(&reason)
+            .original_result()
+    }
+
+    pub fn unhide_audit<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ModerationReason>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+        reason: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unhide_audit")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .argument(&reason)
+            .original_result()
+    }
+
+    pub fn hide_contract<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<ModerationReason>,
+    >(
+        self,
+        contract_hash: Arg0,
+        reason: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hide_contract")
+            .argument(&contract_hash)
+            .argument(&reason)
+            .original_result()
+    }
+
+    pub fn unhide_contract<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<ModerationReason>,
+    >(
+        self,
+        contract_hash: Arg0,
+        reason: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unhide_contract")
+            .argument(&contract_hash)
+            .argument(&reason)
+            .original_result()
+    }
+
+    pub fn block_auditor<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ModerationReason>,
+    >(
+        self,
+        auditor: Arg0,
+        reason: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("block_auditor")
+            .argument(&auditor)
+            .argument(&reason)
+            .original_result()
+    }
+
+    pub fn unblock_auditor<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ModerationReason>,
+    >(
+        self,
+        auditor: Arg0,
+        reason: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unblock_auditor")
+            .argument(&auditor)
+            .argument(&reason)
+            .original_result()
+    }
+
+    pub fn is_audit_hidden<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAuditHidden")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .original_result()
+    }
+
+    pub fn get_moderation_log_length(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getModerationLogLength")
+            .original_result()
+    }
+
+    pub fn get_moderation_log<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        start_index: Arg0,
+        limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ModerationLogEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getModerationLog")
+            .argument(&start_index)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn moderators(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getModerators")
+            .original_result()
+    }
+
+    pub fn get_remaining_quota<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        auditor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u32, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRemainingQuota")
+            .argument(&auditor)
+            .original_result()
+    }
+
+    pub fn get_next_allowed_audit_time<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        auditor: Arg0,
+        contract_hash: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNextAllowedAuditTime")
+            .argument(&auditor)
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn get_rate_limits(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RateLimits> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRateLimits")
+            .original_result()
+    }
+
+    pub fn review_audit<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ReviewVerdict>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+        verdict: Arg2,
+        comment: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("review_audit")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .argument(&verdict)
+            .argument(&comment)
+            .original_result()
+    }
+
+    pub fn get_audit_reviews<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PeerReview<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAuditReviews")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .original_result()
+    }
+
+    pub fn get_auditor_reputation<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        auditor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, AuditorReputation> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAuditorReputation")
+            .argument(&auditor)
+            .original_result()
+    }
+
+    pub fn set_governance_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_governance_token")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn vote_on_audit<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<bool>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+        upvote: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("vote_on_audit")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .argument(&upvote)
+            .original_result()
+    }
+
+    pub fn unlock_votes<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unlock_votes")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .original_result()
+    }
+
+    pub fn get_voting_window_end<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVotingWindowEnd")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .original_result()
+    }
+
+    pub fn get_audit_votes<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, VoteTally<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAuditVotes")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .original_result()
+    }
+
+    pub fn get_vote_lock<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+        voter: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<VoteLock<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoteLock")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .argument(&voter)
+            .original_result()
+    }
+
+    pub fn governance_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGovernanceToken")
+            .original_result()
+    }
+
+    pub fn voting_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVotingPeriod")
+            .original_result()
+    }
+
+    pub fn get_audit_commitment<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        commitment_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<AuditCommitment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAuditCommitment")
+            .argument(&commitment_id)
+            .original_result()
+    }
+
+    pub fn get_pending_commitments<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingCommitments")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn get_published_commitment<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<AuditCommitment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPublishedCommitment")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .original_result()
+    }
+
+    pub fn deposit_coverage_capital(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit_coverage_capital")
+            .original_result()
+    }
+
+    pub fn withdraw_coverage_capital<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        shares: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdraw_coverage_capital")
+            .argument(&shares)
+            .original_result()
+    }
+
+    pub fn buy_coverage<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        contract_hash: Arg0,
+        covered_amount: Arg1,
+        period: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, usize> {
+        self.wrapped_tx
+            .raw_call("buy_coverage")
+            .argument(&contract_hash)
+            .argument(&covered_amount)
+            .argument(&period)
+            .original_result()
+    }
+
+    pub fn file_coverage_claim<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        policy_id: Arg0,
+        evidence_hash: Arg1,
+        exploited_at: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("file_coverage_claim")
+            .argument(&policy_id)
+            .argument(&evidence_hash)
+            .argument(&exploited_at)
+            .original_result()
+    }
+
+    pub fn vote_on_claim<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        policy_id: Arg0,
+        approve: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("vote_on_claim")
+            .argument(&policy_id)
+            .argument(&approve)
+            .original_result()
+    }
+
+    pub fn expire_coverage_claim<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        policy_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("expire_coverage_claim")
+            .argument(&policy_id)
+            .original_result()
+    }
+
+    pub fn release_coverage<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        policy_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("release_coverage")
+            .argument(&policy_id)
+            .original_result()
+    }
+
+    pub fn get_coverage_terms(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<CoverageTerms>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCoverageTerms")
+            .original_result()
+    }
+
+    pub fn quote_premium<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        covered_amount: Arg0,
+        period: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("quotePremium")
+            .argument(&covered_amount)
+            .argument(&period)
+            .original_result()
+    }
+
+    pub fn get_coverage_pool(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCoveragePool")
+            .original_result()
+    }
+
+    pub fn get_underwriter_position<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        underwriter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnderwriterPosition")
+            .argument(&underwriter)
+            .original_result()
+    }
+
+    pub fn get_coverage_policy<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        policy_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, CoveragePolicy<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCoveragePolicy")
+            .argument(&policy_id)
+            .original_result()
+    }
+
+    pub fn get_coverage_claim<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        policy_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<CoverageClaim<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCoverageClaim")
+            .argument(&policy_id)
+            .original_result()
+    }
+
+    pub fn get_contract_policies<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractPolicies")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn arbiters(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getArbiters")
+            .original_result()
+    }
+
+    pub fn propose<
+        Arg0: ProxyArg<ProposalAction<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        action: Arg0,
+        description: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("propose")
+            .argument(&action)
+            .argument(&description)
+            .original_result()
+    }
+
+    pub fn vote_proposal<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        proposal_id: Arg0,
+        approve: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("vote_proposal")
+            .argument(&proposal_id)
+            .argument(&approve)
+            .original_result()
+    }
+
+    pub fn execute_proposal<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("execute_proposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn veto_proposal<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("veto_proposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Proposal<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalCount")
+            .original_result()
+    }
+
+    pub fn get_proposal_status<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProposalStatus> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalStatus")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn has_voted_on_proposal<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        voter: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasVotedOnProposal")
+            .argument(&proposal_id)
+            .argument(&voter)
+            .original_result()
+    }
+
+    pub fn link_project<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_hash: Arg0,
+        sc_address: Arg1,
+        project_owner: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("link_project")
+            .argument(&contract_hash)
+            .argument(&sc_address)
+            .argument(&project_owner)
+            .original_result()
+    }
+
+    pub fn unlink_project<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unlink_project")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn respond_to_audit<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ResponseStatus>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<OptionalValue<ManagedByteArray<Env::Api, 32usize>>>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+        status: Arg2,
+        comment: Arg3,
+        fixed_code_hash: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("respond_to_audit")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .argument(&status)
+            .argument(&comment)
+            .argument(&fixed_code_hash)
+            .original_result()
+    }
+
+    pub fn get_project_link<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ProjectLink<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProjectLink")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn get_audit_response<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ProjectResponse<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAuditResponse")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .original_result()
+    }
+
+    pub fn get_remediation_audits<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Audit<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRemediationAudits")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .original_result()
+    }
+
+    pub fn get_visible_remediation_audits<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Audit<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVisibleRemediationAudits")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .original_result()
+    }
+
+    pub fn reject_self_audits(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isRejectingSelfAudits")
+            .original_result()
+    }
+
+    pub fn record_upgrade<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        sc_address: Arg0,
+        new_code_hash: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("record_upgrade")
+            .argument(&sc_address)
+            .argument(&new_code_hash)
+            .original_result()
+    }
+
+    pub fn get_contract_lineage<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, CodeVersion<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractLineage")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    pub fn get_lineage_audits<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<ManagedByteArray<Env::Api, 32usize>, usize, Audit<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLineageAudits")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    pub fn get_visible_lineage_audits<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<ManagedByteArray<Env::Api, 32usize>, usize, Audit<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVisibleLineageAudits")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    pub fn set_contract_category<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<ContractCategory>,
+    >(
+        self,
+        contract_hash: Arg0,
+        category: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_contract_category")
+            .argument(&contract_hash)
+            .argument(&category)
+            .original_result()
+    }
+
+    pub fn add_contract_tags<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        contract_hash: Arg0,
+        tags: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("add_contract_tags")
+            .argument(&contract_hash)
+            .argument(&tags)
+            .original_result()
+    }
+
+    pub fn remove_contract_tags<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        contract_hash: Arg0,
+        tags: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("remove_contract_tags")
+            .argument(&contract_hash)
+            .argument(&tags)
+            .original_result()
+    }
+
+    pub fn get_contract_category<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ContractCategory>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractCategory")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn get_contract_tags<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractTags")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn get_contracts_by_tag<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        tag: Arg0,
+        start_index: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedByteArray<Env::Api, 32usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractsByTag")
+            .argument(&tag)
+            .argument(&start_index)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_visible_contracts_by_tag<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        tag: Arg0,
+        start_index: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedByteArray<Env::Api, 32usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVisibleContractsByTag")
+            .argument(&tag)
+            .argument(&start_index)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_total_contracts_by_tag<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        tag: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalContractsByTag")
+            .argument(&tag)
+            .original_result()
+    }
+
+    pub fn get_contracts_by_category<
+        Arg0: ProxyArg<ContractCategory>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        category: Arg0,
+        start_index: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedByteArray<Env::Api, 32usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractsByCategory")
+            .argument(&category)
+            .argument(&start_index)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_visible_contracts_by_category<
+        Arg0: ProxyArg<ContractCategory>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        category: Arg0,
+        start_index: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedByteArray<Env::Api, 32usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVisibleContractsByCategory")
+            .argument(&category)
+            .argument(&start_index)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_total_contracts_by_category<
+        Arg0: ProxyArg<ContractCategory>,
+    >(
+        self,
+        category: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalContractsByCategory")
+            .argument(&category)
+            .original_result()
+    }
+
+    pub fn get_team_audit_draft<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        draft_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<TeamAuditDraft<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTeamAuditDraft")
+            .argument(&draft_id)
+            .original_result()
+    }
+
+    pub fn get_team_audit_signatures<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        draft_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTeamAuditSignatures")
+            .argument(&draft_id)
+            .original_result()
+    }
+
+    pub fn get_audit_signers<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        audit_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAuditSigners")
+            .argument(&contract_hash)
+            .argument(&audit_index)
+            .original_result()
+    }
+
+    pub fn get_audits_between<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<usize>,
+    >(
+        self,
+        from_timestamp: Arg0,
+        to_timestamp: Arg1,
+        start_index: Arg2,
+        limit: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<ManagedByteArray<Env::Api, 32usize>, usize, Audit<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAuditsBetween")
+            .argument(&from_timestamp)
+            .argument(&to_timestamp)
+            .argument(&start_index)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_contracts_by_min_stars<
+        Arg0: ProxyArg<u8>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        min_stars: Arg0,
+        start_index: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedByteArray<Env::Api, 32usize>, u8>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractsByMinStars")
+            .argument(&min_stars)
+            .argument(&start_index)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_recent_audits<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        start_index: Arg0,
+        limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<ManagedByteArray<Env::Api, 32usize>, usize, Audit<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRecentAudits")
+            .argument(&start_index)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_total_audit_log_entries(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalAuditLogEntries")
+            .original_result()
+    }
+
+    pub fn get_total_contracts_by_stars<
+        Arg0: ProxyArg<u8>,
+    >(
+        self,
+        stars: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalContractsByStars")
+            .argument(&stars)
+            .original_result()
+    }
+
+    pub fn claim_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claim_rewards")
+            .original_result()
+    }
+
+    pub fn get_claimable_rewards<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        auditor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimableRewards")
+            .argument(&auditor)
+            .original_result()
+    }
+
+    pub fn get_total_claimable_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalClaimableRewards")
+            .original_result()
+    }
+
+    pub fn auditor_share_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAuditorShare")
+            .original_result()
+    }
+
+    pub fn queue_admin_action<
+        Arg0: ProxyArg<AdminAction<Env::Api>>,
+    >(
+        self,
+        action: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("queue_admin_action")
+            .argument(&action)
+            .original_result()
+    }
+
+    pub fn cancel_admin_action<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancel_admin_action")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn execute_admin_action<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("execute_admin_action")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn get_admin_action<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, QueuedAction<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAdminAction")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn get_queued_actions(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<usize, QueuedAction<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQueuedActions")
+            .original_result()
+    }
+
+    pub fn get_timelock_delay(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTimelockDelay")
+            .original_result()
+    }
+
+    pub fn approved_upgrade(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getApprovedUpgrade")
+            .original_result()
+    }
+
+    pub fn watch<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("watch")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn unwatch<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unwatch")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn get_watchers<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        contract_hash: Arg0,
+        start_index: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWatchers")
+            .argument(&contract_hash)
+            .argument(&start_index)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_total_watchers<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        contract_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalWatchers")
+            .argument(&contract_hash)
+            .original_result()
+    }
+
+    pub fn get_watched_contracts<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        watcher: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedByteArray<Env::Api, 32usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWatchedContracts")
+            .argument(&watcher)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HashAlgorithm {
+    MultiversXCodeHash,
+    EvmBytecodeKeccak256,
+    SourceHash,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Debug)]
+pub struct AuditProvenance<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub kind: AuditKind,
+    pub model_id: ManagedBuffer<Api>,
+    pub model_version: ManagedBuffer<Api>,
+    pub prompt_template_hash: ManagedBuffer<Api>,
+    pub tool_version: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AuditKind {
+    Unspecified,
+    Human,
+    Ai,
+    Hybrid,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct ContractNamespace<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub chain_id: ManagedBuffer<Api>,
+    pub hash_algorithm: HashAlgorithm,
+    pub contract_hash: ManagedByteArray<Api, 32usize>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Debug)]
+pub struct Audit<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub stars: u8,
+    pub summary: ManagedBuffer<Api>,
+    pub auditor: ManagedAddress<Api>,
+    pub timestamp: u64,
+    pub chain_id: ManagedBuffer<Api>,
+    pub hash_algorithm: HashAlgorithm,
+    pub provenance: AuditProvenance<Api>,
+    pub endorsements: u32,
+    pub contests: u32,
+    pub project_response: ResponseStatus,
+    pub self_attested: bool,
+    pub amended_at: u64,
+    pub revoked: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ResponseStatus {
+    NoResponse,
+    Acknowledged,
+    Disputed,
+    Fixed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct AuditEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub timestamp: u64,
+    pub summary: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct BountyPool<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: u64,
+    pub project: ManagedAddress<Api>,
+    pub token: EgldOrEsdtTokenIdentifier<Api>,
+    pub balance: BigUint<Api>,
+    pub low_reward: BigUint<Api>,
+    pub medium_reward: BigUint<Api>,
+    pub high_reward: BigUint<Api>,
+    pub critical_reward: BigUint<Api>,
+    pub deadline: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct VulnerabilityReport<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub reporter: ManagedAddress<Api>,
+    pub report_hash: ManagedByteArray<Api, 32usize>,
+    pub severity: Severity,
+    pub status: ReportStatus,
+    pub payout: BigUint<Api>,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReportStatus {
+    Pending,
+    Paid,
+    Rejected,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct AuditScope<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub repository_url: ManagedBuffer<Api>,
+    pub commit_hash: ManagedBuffer<Api>,
+    pub compiler_version: ManagedBuffer<Api>,
+    pub files: ManagedVec<Api, ScopeFile<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Debug)]
+pub struct ScopeFile<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub path: ManagedBuffer<Api>,
+    pub file_hash: ManagedByteArray<Api, 32usize>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ModerationReason {
+    Spam,
+    Abusive,
+    Plagiarism,
+    Misleading,
+    AppealAccepted,
+    Other,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct ModerationLogEntry<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub moderator: ManagedAddress<Api>,
+    pub action: ModerationActionKind,
+    pub target: ModerationTarget<Api>,
+    pub reason: ModerationReason,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ModerationActionKind {
+    HideAudit,
+    UnhideAudit,
+    HideContract,
+    UnhideContract,
+    BlockAuditor,
+    UnblockAuditor,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub enum ModerationTarget<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Audit(ManagedByteArray<Api, 32usize>, usize),
+    Contract(ManagedByteArray<Api, 32usize>),
+    Auditor(ManagedAddress<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug, Default)]
+pub struct RateLimits {
+    pub max_audits_per_window: u32,
+    pub window_duration: u64,
+    pub same_contract_cooldown: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReviewVerdict {
+    Endorse,
+    Contest,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct PeerReview<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub reviewer: ManagedAddress<Api>,
+    pub verdict: ReviewVerdict,
+    pub comment: ManagedBuffer<Api>,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct AuditorReputation {
+    pub audited_contracts: u64,
+    pub endorsements_received: u64,
+    pub contests_received: u64,
+    pub score: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct VoteTally<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub upvotes: BigUint<Api>,
+    pub downvotes: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct VoteLock<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub amount: BigUint<Api>,
+    pub upvote: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct AuditCommitment<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub auditor: ManagedAddress<Api>,
+    pub contract_hash: ManagedByteArray<Api, 32usize>,
+    pub commitment: ManagedByteArray<Api, 32usize>,
+    pub committed_at: u64,
+    pub disclosure_time: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct CoverageTerms {
+    pub min_stars: u8,
+    pub premium_bps: u32,
+    pub protocol_fee_bps: u32,
+    pub claim_quorum: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct CoveragePolicy<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub holder: ManagedAddress<Api>,
+    pub contract_hash: ManagedByteArray<Api, 32usize>,
+    pub covered_amount: BigUint<Api>,
+    pub premium: BigUint<Api>,
+    pub start: u64,
+    pub end: u64,
+    pub status: PolicyStatus,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PolicyStatus {
+    Active,
+    ClaimPending,
+    PaidOut,
+    Released,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct CoverageClaim<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub evidence_hash: ManagedByteArray<Api, 32usize>,
+    pub exploited_at: u64,
+    pub filed_at: u64,
+    pub approvals: usize,
+    pub rejections: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub enum ProposalAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    SetMaxSummaryLength(usize),
+    SetAuditValidityPeriod(u64),
+    SetAdmissionRequired(bool),
+    AdmitAuditor(ManagedAddress<Api>),
+    RemoveAuditor(ManagedAddress<Api>),
+    SetRegistrationFee(BigUint<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Proposal<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub proposer: ManagedAddress<Api>,
+    pub action: ProposalAction<Api>,
+    pub description: ManagedBuffer<Api>,
+    pub created_at: u64,
+    pub voting_end: u64,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub executed: bool,
+    pub vetoed: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Active,
+    Defeated,
+    Queued,
+    Executable,
+    Executed,
+    Vetoed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct ProjectLink<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub sc_address: ManagedAddress<Api>,
+    pub project_owner: ManagedAddress<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct ProjectResponse<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub responder: ManagedAddress<Api>,
+    pub status: ResponseStatus,
+    pub fixed_code_hash: Option<ManagedByteArray<Api, 32usize>>,
+    pub comment: ManagedBuffer<Api>,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct CodeVersion<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub code_hash: ManagedByteArray<Api, 32usize>,
+    pub recorded_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ContractCategory {
+    DeFi,
+    Nft,
+    Dao,
+    Bridge,
+    Token,
+    Other,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct TeamAuditDraft<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub lead: ManagedAddress<Api>,
+    pub contract_hash: ManagedByteArray<Api, 32usize>,
+    pub stars: u8,
+    pub summary: ManagedBuffer<Api>,
+    pub co_auditors: ManagedVec<Api, ManagedAddress<Api>>,
+    pub fee: BigUint<Api>,
+    pub proposed_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub enum AdminAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Withdraw,
+    SetRegistrationFee(BigUint<Api>),
+    RemoveAuditor(ManagedAddress<Api>),
+    ApproveUpgrade(ManagedByteArray<Api, 32usize>),
+    SetTimelockDelay(u64),
+    SetAuditorShare(u32),
+    ChangeOwner(ManagedAddress<Api>),
+    AdmitAuditor(ManagedAddress<Api>),
+    SetVotingPeriod(u64),
+    SetRateLimits(RateLimits),
+    SetRejectSelfAudits(bool),
+    SetCoverageTerms(CoverageTerms),
+    AddArbiter(ManagedAddress<Api>),
+    RemoveArbiter(ManagedAddress<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct QueuedAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action: AdminAction<Api>,
+    pub queued_at: u64,
+    pub eta: u64,
+    pub status: AdminActionStatus,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AdminActionStatus {
+    Queued,
+    Cancelled,
+    Executed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WatchActivity {
+    NewAudit,
+    Amendment,
+    Dispute,
+    Revocation,
 }
@@ -0,0 +1,411 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{indexes, moderation, project_response, rewards, storage, watchlist};
+
+// Bounty pools must stay open between one day and one year
+pub const MIN_BOUNTY_DURATION: u64 = 86_400;
+pub const MAX_BOUNTY_DURATION: u64 = 31_536_000;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReportStatus {
+    Pending,
+    Paid,
+    Rejected,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct BountyPool<M: ManagedTypeApi> {
+    // Reports are kept per pool, so a closed pool's reports never carry over
+    pub id: u64,
+    pub project: ManagedAddress<M>,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub balance: BigUint<M>,
+    pub low_reward: BigUint<M>,
+    pub medium_reward: BigUint<M>,
+    pub high_reward: BigUint<M>,
+    pub critical_reward: BigUint<M>,
+    pub deadline: u64,
+}
+
+impl<M: ManagedTypeApi> BountyPool<M> {
+    pub fn reward_for(&self, severity: Severity) -> &BigUint<M> {
+        match severity {
+            Severity::Low => &self.low_reward,
+            Severity::Medium => &self.medium_reward,
+            Severity::High => &self.high_reward,
+            Severity::Critical => &self.critical_reward,
+        }
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct VulnerabilityReport<M: ManagedTypeApi> {
+    pub reporter: ManagedAddress<M>,
+    pub report_hash: ManagedByteArray<M, 32>,
+    pub severity: Severity,
+    pub status: ReportStatus,
+    pub payout: BigUint<M>,
+    pub timestamp: u64,
+}
+
+// Bug bounty pools attached to audited contract hashes
+#[multiversx_sc::module]
+pub trait BountyModule:
+    storage::StorageModule
+    + indexes::IndexesModule
+    + rewards::RewardsModule
+    + moderation::ModerationModule
+    + watchlist::WatchlistModule
+    + project_response::ProjectResponseModule
+{
+    // Only the owner of the linked project contract can open a pool for its hash
+    #[payable("*")]
+    #[endpoint]
+    fn create_bounty(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        duration: u64,
+        low_reward: BigUint,
+        medium_reward: BigUint,
+        high_reward: BigUint,
+        critical_reward: BigUint,
+    ) {
+        require!(
+            self.audit_count(&contract_hash) > 0,
+            "No audits found for this contract"
+        );
+        let link_mapper = self.project_link(&contract_hash);
+        require!(!link_mapper.is_empty(), "Project not linked");
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == link_mapper.get().project_owner,
+            "Only the project owner can create a bounty"
+        );
+        require!(self.bounty_pool(&contract_hash).is_empty(), "Bounty already exists");
+        require!(
+            (MIN_BOUNTY_DURATION..=MAX_BOUNTY_DURATION).contains(&duration),
+            "Invalid bounty duration"
+        );
+        require!(critical_reward > 0, "Critical reward cannot be zero");
+        require!(
+            low_reward <= medium_reward && medium_reward <= high_reward && high_reward <= critical_reward,
+            "Rewards must increase with severity"
+        );
+
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.token_nonce == 0, "Only fungible tokens accepted");
+        require!(payment.amount > 0, "Bounty must be funded");
+
+        let deadline = self.blockchain().get_block_timestamp() + duration;
+        let pool_id = self.last_bounty_id().update(|id| {
+            *id += 1;
+            *id
+        });
+
+        self.lock_funds(&payment.token_identifier, &payment.amount);
+        self.bounty_pool(&contract_hash).set(BountyPool {
+            id: pool_id,
+            project: caller.clone(),
+            token: payment.token_identifier.clone(),
+            balance: payment.amount.clone(),
+            low_reward,
+            medium_reward,
+            high_reward,
+            critical_reward,
+            deadline,
+        });
+        self.bounty_contracts().insert(contract_hash.clone());
+
+        self.bounty_created_event(
+            &contract_hash,
+            pool_id,
+            &caller,
+            &payment.token_identifier,
+            &payment.amount,
+            deadline,
+        );
+    }
+
+    #[payable("*")]
+    #[endpoint]
+    fn fund_bounty(&self, contract_hash: ManagedByteArray<Self::Api, 32>) {
+        let mut pool = self.get_open_pool(&contract_hash);
+
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.token_identifier == pool.token, "Wrong bounty token");
+        require!(payment.amount > 0, "Bounty must be funded");
+
+        self.lock_funds(&pool.token, &payment.amount);
+        pool.balance += &payment.amount;
+        self.bounty_pool(&contract_hash).set(&pool);
+
+        self.bounty_funded_event(
+            &contract_hash,
+            &self.blockchain().get_caller(),
+            &payment.amount,
+        );
+    }
+
+    #[endpoint]
+    fn submit_vulnerability_report(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        report_hash: ManagedByteArray<Self::Api, 32>,
+        severity: Severity,
+    ) {
+        let pool = self.get_open_pool(&contract_hash);
+        let caller = self.blockchain().get_caller();
+        require!(caller != pool.project, "Project cannot report on its own bounty");
+
+        let report = VulnerabilityReport {
+            reporter: caller.clone(),
+            report_hash: report_hash.clone(),
+            severity,
+            status: ReportStatus::Pending,
+            payout: BigUint::zero(),
+            timestamp: self.blockchain().get_block_timestamp(),
+        };
+        let report_index = self.bounty_reports(pool.id).push(&report);
+
+        self.vulnerability_reported_event(
+            &contract_hash,
+            report_index,
+            &caller,
+            &report_hash,
+            severity,
+        );
+    }
+
+    // The project confirms the final severity, which decides the payout tier
+    #[endpoint]
+    fn approve_report(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        report_index: usize,
+        severity: Severity,
+    ) {
+        let mut pool = self.get_project_pool(&contract_hash);
+        let mut report = self.get_pending_report(pool.id, report_index);
+
+        let reward = pool.reward_for(severity).clone();
+        require!(reward > 0, "No reward for this severity");
+        require!(pool.balance > 0, "Bounty pool is empty");
+        let payout = core::cmp::min(reward, pool.balance.clone());
+
+        pool.balance -= &payout;
+        self.bounty_pool(&contract_hash).set(&pool);
+        self.unlock_funds(&pool.token, &payout);
+
+        report.severity = severity;
+        report.status = ReportStatus::Paid;
+        report.payout = payout.clone();
+        self.bounty_reports(pool.id).set(report_index, &report);
+
+        self.send().direct(&report.reporter, &pool.token, 0, &payout);
+
+        self.bounty_paid_event(
+            &contract_hash,
+            report_index,
+            &report.reporter,
+            severity,
+            &payout,
+        );
+    }
+
+    #[endpoint]
+    fn reject_report(&self, contract_hash: ManagedByteArray<Self::Api, 32>, report_index: usize) {
+        let pool = self.get_project_pool(&contract_hash);
+        let mut report = self.get_pending_report(pool.id, report_index);
+
+        report.status = ReportStatus::Rejected;
+        self.bounty_reports(pool.id).set(report_index, &report);
+
+        self.report_rejected_event(&contract_hash, report_index, &report.reporter);
+    }
+
+    // Once the deadline has passed, anyone can return the unclaimed funds to the project
+    #[endpoint]
+    fn close_bounty(&self, contract_hash: ManagedByteArray<Self::Api, 32>) {
+        let pool_mapper = self.bounty_pool(&contract_hash);
+        require!(!pool_mapper.is_empty(), "No bounty for this contract");
+
+        let pool = pool_mapper.get();
+        require!(
+            self.blockchain().get_block_timestamp() >= pool.deadline,
+            "Bounty is still open"
+        );
+
+        pool_mapper.clear();
+        self.bounty_contracts().swap_remove(&contract_hash);
+
+        if pool.balance > 0 {
+            self.unlock_funds(&pool.token, &pool.balance);
+            self.send().direct(&pool.project, &pool.token, 0, &pool.balance);
+        }
+
+        self.bounty_closed_event(&contract_hash, &pool.project, &pool.balance);
+    }
+
+    // Views
+    #[view(getBountyPool)]
+    fn get_bounty_pool(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+    ) -> OptionalValue<BountyPool<Self::Api>> {
+        let pool_mapper = self.bounty_pool(&contract_hash);
+        if pool_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(pool_mapper.get())
+        }
+    }
+
+    // Reports of the open pool of a contract hash
+    #[view(getBountyReports)]
+    fn get_bounty_reports(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+    ) -> MultiValueEncoded<VulnerabilityReport<Self::Api>> {
+        let pool_mapper = self.bounty_pool(&contract_hash);
+        if pool_mapper.is_empty() {
+            return MultiValueEncoded::new();
+        }
+
+        self.get_bounty_pool_reports(pool_mapper.get().id)
+    }
+
+    // Reports of any pool, including closed ones
+    #[view(getBountyPoolReports)]
+    fn get_bounty_pool_reports(&self, pool_id: u64) -> MultiValueEncoded<VulnerabilityReport<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for report in self.bounty_reports(pool_id).iter() {
+            result.push(report);
+        }
+
+        result
+    }
+
+    // Internal helpers
+    fn get_open_pool(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) -> BountyPool<Self::Api> {
+        let pool_mapper = self.bounty_pool(contract_hash);
+        require!(!pool_mapper.is_empty(), "No bounty for this contract");
+
+        let pool = pool_mapper.get();
+        require!(
+            self.blockchain().get_block_timestamp() < pool.deadline,
+            "Bounty has expired"
+        );
+
+        pool
+    }
+
+    fn get_project_pool(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) -> BountyPool<Self::Api> {
+        let pool_mapper = self.bounty_pool(contract_hash);
+        require!(!pool_mapper.is_empty(), "No bounty for this contract");
+
+        let pool = pool_mapper.get();
+        require!(
+            self.blockchain().get_caller() == pool.project,
+            "Only the project can manage its bounty"
+        );
+
+        pool
+    }
+
+    fn get_pending_report(&self, pool_id: u64, report_index: usize) -> VulnerabilityReport<Self::Api> {
+        let reports_mapper = self.bounty_reports(pool_id);
+        require!(
+            report_index >= 1 && report_index <= reports_mapper.len(),
+            "Report not found"
+        );
+
+        let report = reports_mapper.get(report_index);
+        require!(report.status == ReportStatus::Pending, "Report already processed");
+
+        report
+    }
+
+    // Storage mappings
+    #[storage_mapper("bountyPool")]
+    fn bounty_pool(&self, contract_hash: &ManagedByteArray<Self::Api, 32>)
+        -> SingleValueMapper<BountyPool<Self::Api>>;
+
+    #[storage_mapper("lastBountyId")]
+    fn last_bounty_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("bountyReports")]
+    fn bounty_reports(&self, pool_id: u64) -> VecMapper<VulnerabilityReport<Self::Api>>;
+
+    #[view(getActiveBounties)]
+    #[storage_mapper("bountyContracts")]
+    fn bounty_contracts(&self) -> UnorderedSetMapper<ManagedByteArray<Self::Api, 32>>;
+
+    // Events
+    #[event("bountyCreated")]
+    fn bounty_created_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] pool_id: u64,
+        #[indexed] project: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+        deadline: u64,
+    );
+
+    #[event("bountyFunded")]
+    fn bounty_funded_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] funder: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("vulnerabilityReported")]
+    fn vulnerability_reported_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] report_index: usize,
+        #[indexed] reporter: &ManagedAddress,
+        #[indexed] report_hash: &ManagedByteArray<Self::Api, 32>,
+        severity: Severity,
+    );
+
+    #[event("bountyPaid")]
+    fn bounty_paid_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] report_index: usize,
+        #[indexed] reporter: &ManagedAddress,
+        #[indexed] severity: Severity,
+        payout: &BigUint,
+    );
+
+    #[event("reportRejected")]
+    fn report_rejected_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] report_index: usize,
+        reporter: &ManagedAddress,
+    );
+
+    #[event("bountyClosed")]
+    fn bounty_closed_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] project: &ManagedAddress,
+        returned_amount: &BigUint,
+    );
+}
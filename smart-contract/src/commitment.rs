@@ -0,0 +1,132 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::storage;
+
+// Disclosure can be scheduled at most a year ahead
+pub const MAX_DISCLOSURE_DELAY: u64 = 31_536_000;
+
+// A committed audit whose content stays private until its disclosure time
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct AuditCommitment<M: ManagedTypeApi> {
+    pub auditor: ManagedAddress<M>,
+    pub contract_hash: ManagedByteArray<M, 32>,
+    pub commitment: ManagedByteArray<M, 32>,
+    pub committed_at: u64,
+    pub disclosure_time: u64,
+}
+
+// The commitment is the keccak256 of this struct, top-encoded; binding the auditor stops others from reusing it
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct AuditReveal<M: ManagedTypeApi> {
+    pub auditor: ManagedAddress<M>,
+    pub contract_hash: ManagedByteArray<M, 32>,
+    pub stars: u8,
+    pub summary: ManagedBuffer<M>,
+    pub salt: ManagedByteArray<M, 32>,
+}
+
+// Commit-reveal drafts: only the hash is on record until the audit is published
+#[multiversx_sc::module]
+pub trait CommitmentModule: storage::StorageModule {
+    // Not a view: clients hash the reveal locally so the salt stays private until the audit is published
+    fn compute_audit_commitment(
+        &self,
+        auditor: ManagedAddress,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        stars: u8,
+        summary: ManagedBuffer,
+        salt: ManagedByteArray<Self::Api, 32>,
+    ) -> ManagedByteArray<Self::Api, 32> {
+        let reveal = AuditReveal {
+            auditor,
+            contract_hash,
+            stars,
+            summary,
+            salt,
+        };
+
+        let mut encoded = ManagedBuffer::new();
+        reveal.top_encode(&mut encoded).unwrap_or_else(|_| sc_panic!("Encoding failed"));
+        self.crypto().keccak256(&encoded)
+    }
+
+    // Views
+    #[view(getAuditCommitment)]
+    fn get_audit_commitment(&self, commitment_id: u64) -> OptionalValue<AuditCommitment<Self::Api>> {
+        let commitment_mapper = self.audit_commitment(commitment_id);
+        if commitment_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(commitment_mapper.get())
+        }
+    }
+
+    // Ids of the unpublished commitments for a contract hash
+    #[view(getPendingCommitments)]
+    fn get_pending_commitments(&self, contract_hash: ManagedByteArray<Self::Api, 32>) -> MultiValueEncoded<u64> {
+        let mut result = MultiValueEncoded::new();
+        for commitment_id in self.pending_commitments(&contract_hash).iter() {
+            result.push(commitment_id);
+        }
+
+        result
+    }
+
+    // The commitment a published audit was revealed from, if any
+    #[view(getPublishedCommitment)]
+    fn get_published_commitment(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+    ) -> OptionalValue<AuditCommitment<Self::Api>> {
+        let commitment_mapper = self.published_commitment(&contract_hash, audit_index);
+        if commitment_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(commitment_mapper.get())
+        }
+    }
+
+    // Storage mappings
+    #[storage_mapper("lastCommitmentId")]
+    fn last_commitment_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("auditCommitment")]
+    fn audit_commitment(&self, commitment_id: u64) -> SingleValueMapper<AuditCommitment<Self::Api>>;
+
+    // Whether the registration fee was paid at commit time, so the published audit can earn rewards
+    #[storage_mapper("commitmentFeePaid")]
+    fn commitment_fee_paid(&self, commitment_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("pendingCommitments")]
+    fn pending_commitments(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("publishedCommitment")]
+    fn published_commitment(
+        &self,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+    ) -> SingleValueMapper<AuditCommitment<Self::Api>>;
+
+    // Events
+    #[event("auditCommitted")]
+    fn audit_committed_event(
+        &self,
+        #[indexed] commitment_id: u64,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] auditor: &ManagedAddress,
+        #[indexed] commitment: &ManagedByteArray<Self::Api, 32>,
+        disclosure_time: u64,
+    );
+
+    #[event("auditPublished")]
+    fn audit_published_event(
+        &self,
+        #[indexed] commitment_id: u64,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] audit_index: usize,
+    );
+}
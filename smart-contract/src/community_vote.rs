@@ -0,0 +1,210 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::storage;
+
+// Upper bound keeps `timestamp + period` far from overflow and votes from being locked for years
+pub const MAX_VOTING_PERIOD: u64 = 2_592_000;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct VoteTally<M: ManagedTypeApi> {
+    pub upvotes: BigUint<M>,
+    pub downvotes: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct VoteLock<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub upvote: bool,
+}
+
+// Token-weighted community votes on audit quality
+#[multiversx_sc::module]
+pub trait CommunityVoteModule: storage::StorageModule {
+    // The governance token can only be set once, so existing locks always refer to it
+    #[endpoint]
+    fn set_governance_token(&self, token_id: TokenIdentifier) {
+        require!(
+            self.blockchain().get_caller() == self.owner().get(),
+            "Only owner can configure voting"
+        );
+        require!(token_id.is_valid_esdt_identifier(), "Invalid token identifier");
+        require!(self.governance_token().is_empty(), "Governance token already set");
+
+        self.governance_token().set(token_id);
+    }
+
+    // Locks the paid governance tokens as votes until the audit's voting window closes
+    #[payable("*")]
+    #[endpoint]
+    fn vote_on_audit(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        upvote: bool,
+    ) {
+        require!(!self.governance_token().is_empty(), "Voting is not enabled");
+        let payment = self.call_value().single_esdt().clone();
+        require!(
+            payment.token_identifier == self.governance_token().get(),
+            "Wrong voting token"
+        );
+        require!(payment.amount > 0, "Vote amount cannot be zero");
+
+        let window_end = self.get_voting_window_end(contract_hash.clone(), audit_index);
+        require!(
+            self.blockchain().get_block_timestamp() < window_end,
+            "Voting window closed"
+        );
+        // The first vote fixes the window, so later period changes don't move it
+        let window_end_mapper = self.voting_window_end(&contract_hash, audit_index);
+        if window_end_mapper.is_empty() {
+            window_end_mapper.set(window_end);
+        }
+
+        let caller = self.blockchain().get_caller();
+        let lock_mapper = self.vote_lock(&contract_hash, audit_index, &caller);
+        let mut lock = if lock_mapper.is_empty() {
+            VoteLock {
+                amount: BigUint::zero(),
+                upvote,
+            }
+        } else {
+            lock_mapper.get()
+        };
+        require!(lock.upvote == upvote, "Cannot change vote direction");
+
+        lock.amount += &payment.amount;
+        lock_mapper.set(&lock);
+
+        let mut tally = self.get_audit_votes(contract_hash.clone(), audit_index);
+        if upvote {
+            tally.upvotes += &payment.amount;
+        } else {
+            tally.downvotes += &payment.amount;
+        }
+        self.audit_votes(&contract_hash, audit_index).set(tally);
+        self.lock_funds(
+            &EgldOrEsdtTokenIdentifier::esdt(payment.token_identifier),
+            &payment.amount,
+        );
+
+        self.audit_voted_event(&contract_hash, audit_index, &caller, upvote, &payment.amount);
+    }
+
+    // Returns the voter's tokens once the window is over; the tally is kept
+    #[endpoint]
+    fn unlock_votes(&self, contract_hash: ManagedByteArray<Self::Api, 32>, audit_index: usize) {
+        let window_end = self.get_voting_window_end(contract_hash.clone(), audit_index);
+        require!(
+            self.blockchain().get_block_timestamp() >= window_end,
+            "Voting window still open"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let lock_mapper = self.vote_lock(&contract_hash, audit_index, &caller);
+        require!(!lock_mapper.is_empty(), "No locked votes");
+
+        let lock = lock_mapper.take();
+        let token = EgldOrEsdtTokenIdentifier::esdt(self.governance_token().get());
+        self.unlock_funds(&token, &lock.amount);
+        self.send().direct(&caller, &token, 0, &lock.amount);
+
+        self.votes_unlocked_event(&contract_hash, audit_index, &caller, &lock.amount);
+    }
+
+    // Views
+    // Snapshotted at the first vote; before that it follows the current period
+    #[view(getVotingWindowEnd)]
+    fn get_voting_window_end(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+    ) -> u64 {
+        let window_end_mapper = self.voting_window_end(&contract_hash, audit_index);
+        if !window_end_mapper.is_empty() {
+            return window_end_mapper.get();
+        }
+
+        self.audit_header(&contract_hash, audit_index).timestamp + self.voting_period().get()
+    }
+
+    #[view(getAuditVotes)]
+    fn get_audit_votes(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+    ) -> VoteTally<Self::Api> {
+        let tally_mapper = self.audit_votes(&contract_hash, audit_index);
+        if tally_mapper.is_empty() {
+            VoteTally {
+                upvotes: BigUint::zero(),
+                downvotes: BigUint::zero(),
+            }
+        } else {
+            tally_mapper.get()
+        }
+    }
+
+    #[view(getVoteLock)]
+    fn get_vote_lock(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        voter: ManagedAddress,
+    ) -> OptionalValue<VoteLock<Self::Api>> {
+        let lock_mapper = self.vote_lock(&contract_hash, audit_index, &voter);
+        if lock_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(lock_mapper.get())
+        }
+    }
+
+    // Storage mappings
+    #[view(getGovernanceToken)]
+    #[storage_mapper("governanceToken")]
+    fn governance_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getVotingPeriod)]
+    #[storage_mapper("votingPeriod")]
+    fn voting_period(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("votingWindowEnd")]
+    fn voting_window_end(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize)
+        -> SingleValueMapper<u64>;
+
+    #[storage_mapper("auditVotes")]
+    fn audit_votes(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize)
+        -> SingleValueMapper<VoteTally<Self::Api>>;
+
+    #[storage_mapper("voteLock")]
+    fn vote_lock(
+        &self,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<VoteLock<Self::Api>>;
+
+    // Events
+    #[event("auditVoted")]
+    fn audit_voted_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] audit_index: usize,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] upvote: bool,
+        amount: &BigUint,
+    );
+
+    #[event("votesUnlocked")]
+    fn votes_unlocked_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] audit_index: usize,
+        #[indexed] voter: &ManagedAddress,
+        amount: &BigUint,
+    );
+}
@@ -0,0 +1,506 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{indexes, moderation, rewards, storage};
+
+// Policies run between one day and one year; premiums are quoted per year of cover
+pub const MIN_COVERAGE_PERIOD: u64 = 86_400;
+pub const MAX_COVERAGE_PERIOD: u64 = 31_536_000;
+pub const MAX_BPS: u32 = 10_000;
+
+// Arbiters have two weeks to settle a claim before it lapses
+pub const CLAIM_VOTING_PERIOD: u64 = 1_209_600;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct CoverageTerms {
+    // Stars the latest visible audit of a contract needs to be covered
+    pub min_stars: u8,
+    // Yearly premium, in basis points of the covered amount
+    pub premium_bps: u32,
+    // Part of each premium kept by the registry treasury instead of the pool
+    pub protocol_fee_bps: u32,
+    // Arbiter votes needed to settle a claim either way
+    pub claim_quorum: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PolicyStatus {
+    Active,
+    ClaimPending,
+    PaidOut,
+    Released,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct CoveragePolicy<M: ManagedTypeApi> {
+    pub holder: ManagedAddress<M>,
+    pub contract_hash: ManagedByteArray<M, 32>,
+    pub covered_amount: BigUint<M>,
+    pub premium: BigUint<M>,
+    pub start: u64,
+    pub end: u64,
+    pub status: PolicyStatus,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct CoverageClaim<M: ManagedTypeApi> {
+    pub evidence_hash: ManagedByteArray<M, 32>,
+    pub exploited_at: u64,
+    pub filed_at: u64,
+    pub approvals: usize,
+    pub rejections: usize,
+}
+
+// Optional EGLD pool insuring holders of well-rated audits against confirmed exploits
+#[multiversx_sc::module]
+pub trait CoverageModule:
+    storage::StorageModule + indexes::IndexesModule + rewards::RewardsModule + moderation::ModerationModule
+{
+    // Underwriters hold pool shares, so premiums and payouts are shared pro rata
+    #[payable("EGLD")]
+    #[endpoint]
+    fn deposit_coverage_capital(&self) {
+        self.require_coverage_enabled();
+        let amount = self.call_value().egld().clone_value();
+        require!(amount > 0, "Deposit cannot be zero");
+
+        let total_capital = self.coverage_capital().get();
+        let total_shares = self.coverage_shares().get();
+        require!(total_capital > 0 || total_shares == 0, "Coverage pool is depleted");
+        let shares = if total_shares == 0 {
+            amount.clone()
+        } else {
+            &amount * &total_shares / &total_capital
+        };
+
+        let caller = self.blockchain().get_caller();
+        self.underwriter_shares(&caller).update(|held| *held += &shares);
+        self.coverage_shares().update(|total| *total += &shares);
+        self.coverage_capital().update(|capital| *capital += &amount);
+        self.lock_funds(&EgldOrEsdtTokenIdentifier::egld(), &amount);
+
+        self.coverage_capital_deposited_event(&caller, &amount, &shares);
+    }
+
+    // Capital backing active policies stays in the pool until they end
+    #[endpoint]
+    fn withdraw_coverage_capital(&self, shares: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let held_shares = self.underwriter_shares(&caller).get();
+        require!(shares > 0 && shares <= held_shares, "Invalid share amount");
+
+        let total_capital = self.coverage_capital().get();
+        let amount = &shares * &total_capital / &self.coverage_shares().get();
+        require!(
+            &total_capital - &amount >= self.active_coverage().get(),
+            "Capital backs active coverage"
+        );
+
+        self.underwriter_shares(&caller).set(&held_shares - &shares);
+        self.coverage_shares().update(|total| *total -= &shares);
+        self.coverage_capital().set(&total_capital - &amount);
+        self.unlock_funds(&EgldOrEsdtTokenIdentifier::egld(), &amount);
+        self.send().direct_egld(&caller, &amount);
+
+        self.coverage_capital_withdrawn_event(&caller, &amount, &shares);
+    }
+
+    // The premium is paid up front and has to match quote_premium exactly
+    #[payable("EGLD")]
+    #[endpoint]
+    fn buy_coverage(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        covered_amount: BigUint,
+        period: u64,
+    ) -> usize {
+        let terms = self.require_coverage_enabled();
+        require!(covered_amount > 0, "Covered amount cannot be zero");
+        require!(
+            (MIN_COVERAGE_PERIOD..=MAX_COVERAGE_PERIOD).contains(&period),
+            "Invalid coverage period"
+        );
+        require!(
+            self.latest_coverable_stars(&contract_hash)
+                .is_some_and(|stars| stars >= terms.min_stars),
+            "Contract audit does not qualify for coverage"
+        );
+
+        let active_coverage = self.active_coverage().get() + &covered_amount;
+        require!(
+            active_coverage <= self.coverage_capital().get(),
+            "Insufficient coverage capital"
+        );
+
+        let premium = self.premium_for(&terms, &covered_amount, period);
+        require!(premium > 0, "Covered amount too small");
+        require!(
+            *self.call_value().egld() == premium,
+            "Premium must match the quoted amount"
+        );
+
+        // The protocol fee stays unlocked for the treasury withdrawal, the rest grows the pool
+        let protocol_fee = &premium * terms.protocol_fee_bps / MAX_BPS;
+        let pool_premium = &premium - &protocol_fee;
+        self.coverage_capital().update(|capital| *capital += &pool_premium);
+        self.lock_funds(&EgldOrEsdtTokenIdentifier::egld(), &pool_premium);
+        self.active_coverage().set(&active_coverage);
+
+        let caller = self.blockchain().get_caller();
+        let start = self.blockchain().get_block_timestamp();
+        let policy_id = self.coverage_policies().push(&CoveragePolicy {
+            holder: caller.clone(),
+            contract_hash: contract_hash.clone(),
+            covered_amount: covered_amount.clone(),
+            premium: premium.clone(),
+            start,
+            end: start + period,
+            status: PolicyStatus::Active,
+        });
+        self.contract_policies(&contract_hash).push(&policy_id);
+
+        self.coverage_bought_event(&contract_hash, policy_id, &caller, &covered_amount, &premium);
+        policy_id
+    }
+
+    // Claims are filed during the coverage period for an exploit that happened within it
+    #[endpoint]
+    fn file_coverage_claim(
+        &self,
+        policy_id: usize,
+        evidence_hash: ManagedByteArray<Self::Api, 32>,
+        exploited_at: u64,
+    ) {
+        let mut policy = self.coverage_policy(policy_id);
+        require!(
+            self.blockchain().get_caller() == policy.holder,
+            "Only the policy holder can claim"
+        );
+        require!(policy.status == PolicyStatus::Active, "Policy is not active");
+
+        let filed_at = self.blockchain().get_block_timestamp();
+        require!(filed_at <= policy.end, "Coverage period has ended");
+        require!(
+            exploited_at >= policy.start && exploited_at <= filed_at,
+            "Exploit outside the coverage period"
+        );
+
+        policy.status = PolicyStatus::ClaimPending;
+        self.coverage_policies().set(policy_id, &policy);
+        self.claim_voters(policy_id).clear();
+        self.coverage_claim(policy_id).set(CoverageClaim {
+            evidence_hash: evidence_hash.clone(),
+            exploited_at,
+            filed_at,
+            approvals: 0,
+            rejections: 0,
+        });
+
+        self.coverage_claim_filed_event(&policy.contract_hash, policy_id, &policy.holder, &evidence_hash);
+    }
+
+    // The first side to reach the quorum settles the claim; a rejected claim reactivates the policy
+    #[endpoint]
+    fn vote_on_claim(&self, policy_id: usize, approve: bool) {
+        let caller = self.blockchain().get_caller();
+        require!(self.arbiters().contains(&caller), "Only arbiters can vote on claims");
+
+        let mut policy = self.coverage_policy(policy_id);
+        require!(policy.status == PolicyStatus::ClaimPending, "No pending claim");
+        let mut claim = self.coverage_claim(policy_id).get();
+        require!(
+            self.blockchain().get_block_timestamp() <= claim.filed_at + CLAIM_VOTING_PERIOD,
+            "Claim voting has ended"
+        );
+        require!(self.claim_voters(policy_id).insert(caller.clone()), "Already voted");
+
+        if approve {
+            claim.approvals += 1;
+        } else {
+            claim.rejections += 1;
+        }
+        self.coverage_claim(policy_id).set(&claim);
+        self.claim_voted_event(policy_id, &caller, approve);
+
+        let quorum = self.coverage_terms().get().claim_quorum;
+        if claim.approvals >= quorum {
+            policy.status = PolicyStatus::PaidOut;
+            self.coverage_policies().set(policy_id, &policy);
+            self.active_coverage().update(|active| *active -= &policy.covered_amount);
+            self.coverage_capital().update(|capital| *capital -= &policy.covered_amount);
+            self.unlock_funds(&EgldOrEsdtTokenIdentifier::egld(), &policy.covered_amount);
+            self.send().direct_egld(&policy.holder, &policy.covered_amount);
+
+            self.coverage_claim_settled_event(&policy.contract_hash, policy_id, true, &policy.covered_amount);
+        } else if claim.rejections >= quorum {
+            policy.status = PolicyStatus::Active;
+            self.coverage_policies().set(policy_id, &policy);
+
+            self.coverage_claim_settled_event(&policy.contract_hash, policy_id, false, &BigUint::zero());
+        }
+    }
+
+    // A claim that misses the quorum lapses like a rejection, so it cannot lock the capital
+    // backing the policy forever; anyone can expire it once voting has ended
+    #[endpoint]
+    fn expire_coverage_claim(&self, policy_id: usize) {
+        let mut policy = self.coverage_policy(policy_id);
+        require!(policy.status == PolicyStatus::ClaimPending, "No pending claim");
+        let claim = self.coverage_claim(policy_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            current_timestamp > claim.filed_at + CLAIM_VOTING_PERIOD,
+            "Claim voting still open"
+        );
+
+        self.coverage_claim_expired_event(&policy.contract_hash, policy_id);
+        if current_timestamp > policy.end {
+            policy.status = PolicyStatus::Released;
+            self.active_coverage().update(|active| *active -= &policy.covered_amount);
+            self.coverage_released_event(&policy.contract_hash, policy_id);
+        } else {
+            policy.status = PolicyStatus::Active;
+        }
+        self.coverage_policies().set(policy_id, &policy);
+    }
+
+    // Once a policy has ended without a pending claim, anyone can free the capital backing it
+    #[endpoint]
+    fn release_coverage(&self, policy_id: usize) {
+        let mut policy = self.coverage_policy(policy_id);
+        require!(policy.status == PolicyStatus::Active, "Policy is not active");
+        require!(
+            self.blockchain().get_block_timestamp() > policy.end,
+            "Coverage period has not ended"
+        );
+
+        policy.status = PolicyStatus::Released;
+        self.coverage_policies().set(policy_id, &policy);
+        self.active_coverage().update(|active| *active -= &policy.covered_amount);
+
+        self.coverage_released_event(&policy.contract_hash, policy_id);
+    }
+
+    // Views
+    #[view(getCoverageTerms)]
+    fn get_coverage_terms(&self) -> OptionalValue<CoverageTerms> {
+        let terms_mapper = self.coverage_terms();
+        if terms_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(terms_mapper.get())
+        }
+    }
+
+    #[view(quotePremium)]
+    fn quote_premium(&self, covered_amount: BigUint, period: u64) -> BigUint {
+        let terms = self.require_coverage_enabled();
+        self.premium_for(&terms, &covered_amount, period)
+    }
+
+    // Total capital, total shares and the amount backing active policies
+    #[view(getCoveragePool)]
+    fn get_coverage_pool(&self) -> MultiValue3<BigUint, BigUint, BigUint> {
+        (
+            self.coverage_capital().get(),
+            self.coverage_shares().get(),
+            self.active_coverage().get(),
+        )
+            .into()
+    }
+
+    // Shares held by an underwriter and their current value
+    #[view(getUnderwriterPosition)]
+    fn get_underwriter_position(&self, underwriter: ManagedAddress) -> MultiValue2<BigUint, BigUint> {
+        let shares = self.underwriter_shares(&underwriter).get();
+        let total_shares = self.coverage_shares().get();
+        let value = if total_shares == 0 {
+            BigUint::zero()
+        } else {
+            &shares * &self.coverage_capital().get() / &total_shares
+        };
+
+        (shares, value).into()
+    }
+
+    #[view(getCoveragePolicy)]
+    fn get_coverage_policy(&self, policy_id: usize) -> CoveragePolicy<Self::Api> {
+        self.coverage_policy(policy_id)
+    }
+
+    #[view(getCoverageClaim)]
+    fn get_coverage_claim(&self, policy_id: usize) -> OptionalValue<CoverageClaim<Self::Api>> {
+        let claim_mapper = self.coverage_claim(policy_id);
+        if claim_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(claim_mapper.get())
+        }
+    }
+
+    #[view(getContractPolicies)]
+    fn get_contract_policies(&self, contract_hash: ManagedByteArray<Self::Api, 32>) -> MultiValueEncoded<usize> {
+        let mut result = MultiValueEncoded::new();
+        for policy_id in self.contract_policies(&contract_hash).iter() {
+            result.push(policy_id);
+        }
+
+        result
+    }
+
+    // Internal helpers
+    // Coverage stays disabled until terms are set through the admin timelock
+    fn require_valid_coverage_terms(&self, terms: &CoverageTerms) {
+        require!(terms.min_stars <= 5, "Stars must be between 0 and 5");
+        require!(
+            terms.premium_bps > 0 && terms.premium_bps <= MAX_BPS,
+            "Premium out of range"
+        );
+        require!(terms.protocol_fee_bps <= MAX_BPS, "Protocol fee out of range");
+        require!(terms.claim_quorum > 0, "Claim quorum cannot be zero");
+    }
+
+    fn change_coverage_terms(&self, terms: &CoverageTerms) {
+        self.coverage_terms().set(terms);
+        self.coverage_terms_set_event(
+            terms.min_stars,
+            terms.premium_bps,
+            terms.protocol_fee_bps,
+            terms.claim_quorum,
+        );
+    }
+
+    fn require_coverage_enabled(&self) -> CoverageTerms {
+        let terms_mapper = self.coverage_terms();
+        require!(!terms_mapper.is_empty(), "Coverage is not enabled");
+
+        terms_mapper.get()
+    }
+
+    fn premium_for(&self, terms: &CoverageTerms, covered_amount: &BigUint, period: u64) -> BigUint {
+        covered_amount * terms.premium_bps * period / (MAX_BPS as u64 * MAX_COVERAGE_PERIOD)
+    }
+
+    // Stars of the latest audit that is neither revoked nor hidden by moderators
+    fn latest_coverable_stars(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) -> Option<u8> {
+        if self.hidden_contract(contract_hash).get() {
+            return None;
+        }
+
+        let headers_mapper = self.audit_headers(contract_hash);
+        (1..=headers_mapper.len())
+            .rev()
+            .map(|audit_index| (audit_index, headers_mapper.get(audit_index)))
+            .find(|(audit_index, header)| !header.revoked && !self.hidden_audit(contract_hash, *audit_index).get())
+            .map(|(_, header)| header.stars)
+    }
+
+    fn coverage_policy(&self, policy_id: usize) -> CoveragePolicy<Self::Api> {
+        let policies_mapper = self.coverage_policies();
+        require!(
+            policy_id >= 1 && policy_id <= policies_mapper.len(),
+            "Policy not found"
+        );
+
+        policies_mapper.get(policy_id)
+    }
+
+    // Storage mappings
+    #[storage_mapper("coverageTerms")]
+    fn coverage_terms(&self) -> SingleValueMapper<CoverageTerms>;
+
+    #[view(getArbiters)]
+    #[storage_mapper("arbiters")]
+    fn arbiters(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("coverageCapital")]
+    fn coverage_capital(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("coverageShares")]
+    fn coverage_shares(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("activeCoverage")]
+    fn active_coverage(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("underwriterShares")]
+    fn underwriter_shares(&self, underwriter: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("coveragePolicies")]
+    fn coverage_policies(&self) -> VecMapper<CoveragePolicy<Self::Api>>;
+
+    #[storage_mapper("contractPolicies")]
+    fn contract_policies(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) -> VecMapper<usize>;
+
+    #[storage_mapper("coverageClaim")]
+    fn coverage_claim(&self, policy_id: usize) -> SingleValueMapper<CoverageClaim<Self::Api>>;
+
+    #[storage_mapper("claimVoters")]
+    fn claim_voters(&self, policy_id: usize) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Events
+    #[event("coverageTermsSet")]
+    fn coverage_terms_set_event(
+        &self,
+        #[indexed] min_stars: u8,
+        #[indexed] premium_bps: u32,
+        #[indexed] protocol_fee_bps: u32,
+        claim_quorum: usize,
+    );
+
+    #[event("coverageCapitalDeposited")]
+    fn coverage_capital_deposited_event(
+        &self,
+        #[indexed] underwriter: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+        shares: &BigUint,
+    );
+
+    #[event("coverageCapitalWithdrawn")]
+    fn coverage_capital_withdrawn_event(
+        &self,
+        #[indexed] underwriter: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+        shares: &BigUint,
+    );
+
+    #[event("coverageBought")]
+    fn coverage_bought_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] policy_id: usize,
+        #[indexed] holder: &ManagedAddress,
+        #[indexed] covered_amount: &BigUint,
+        premium: &BigUint,
+    );
+
+    #[event("coverageClaimFiled")]
+    fn coverage_claim_filed_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] policy_id: usize,
+        #[indexed] holder: &ManagedAddress,
+        evidence_hash: &ManagedByteArray<Self::Api, 32>,
+    );
+
+    #[event("claimVoted")]
+    fn claim_voted_event(&self, #[indexed] policy_id: usize, #[indexed] arbiter: &ManagedAddress, approve: bool);
+
+    #[event("coverageClaimSettled")]
+    fn coverage_claim_settled_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] policy_id: usize,
+        #[indexed] approved: bool,
+        payout: &BigUint,
+    );
+
+    #[event("coverageClaimExpired")]
+    fn coverage_claim_expired_event(&self, #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>, #[indexed] policy_id: usize);
+
+    #[event("coverageReleased")]
+    fn coverage_released_event(&self, #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>, #[indexed] policy_id: usize);
+}
@@ -0,0 +1,108 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::storage;
+
+// Emitted as the first topic of every event below except auditRegistered; bumped whenever a layout changes
+pub const EVENT_SCHEMA_VERSION: u8 = 1;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct AuditEventData<M: ManagedTypeApi> {
+    pub timestamp: u64,
+    pub summary: ManagedBuffer<M>,
+}
+
+// Versioned events for every registry state change, so indexers can rebuild storage
+#[multiversx_sc::module]
+pub trait EventsModule: storage::StorageModule {
+    // Parameter changes shared by governance and the admin timelock
+    fn change_registration_fee(&self, new_fee: &BigUint) {
+        let previous_fee = self.registration_fee().replace(new_fee);
+        self.registration_fee_changed_event(EVENT_SCHEMA_VERSION, &previous_fee, new_fee);
+    }
+
+    fn admit_auditor(&self, auditor: &ManagedAddress) {
+        if self.admitted_auditors().insert(auditor.clone()) {
+            let timestamp = self.blockchain().get_block_timestamp();
+            self.auditor_admitted_event(EVENT_SCHEMA_VERSION, auditor, timestamp);
+        }
+    }
+
+    fn remove_auditor(&self, auditor: &ManagedAddress) {
+        if self.admitted_auditors().swap_remove(auditor) {
+            let timestamp = self.blockchain().get_block_timestamp();
+            self.auditor_removed_event(EVENT_SCHEMA_VERSION, auditor, timestamp);
+        }
+    }
+
+    // Keeps the topics and data of the unversioned event that deployed frontends decode;
+    // the newer fields are appended after them
+    #[event("auditRegistered")]
+    fn audit_registered_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] stars: u8,
+        #[indexed] auditor: &ManagedAddress,
+        #[indexed] self_attested: bool,
+        #[indexed] version: u8,
+        #[indexed] audit_index: usize,
+        #[indexed] timestamp: u64,
+        summary: &ManagedBuffer,
+    );
+
+    #[event("auditAmended")]
+    fn audit_amended_event(
+        &self,
+        #[indexed] version: u8,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] audit_index: usize,
+        #[indexed] auditor: &ManagedAddress,
+        #[indexed] stars: u8,
+        data: &AuditEventData<Self::Api>,
+    );
+
+    #[event("auditRevoked")]
+    fn audit_revoked_event(
+        &self,
+        #[indexed] version: u8,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] audit_index: usize,
+        #[indexed] auditor: &ManagedAddress,
+        timestamp: u64,
+    );
+
+    #[event("legacyAuditsMigrated")]
+    fn legacy_audits_migrated_event(
+        &self,
+        #[indexed] version: u8,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        migrated_count: usize,
+    );
+
+    #[event("ownerChanged")]
+    fn owner_changed_event(
+        &self,
+        #[indexed] version: u8,
+        #[indexed] previous_owner: &ManagedAddress,
+        #[indexed] new_owner: &ManagedAddress,
+        timestamp: u64,
+    );
+
+    #[event("fundsWithdrawn")]
+    fn funds_withdrawn_event(&self, #[indexed] version: u8, #[indexed] recipient: &ManagedAddress, amount: &BigUint);
+
+    #[event("registrationFeeChanged")]
+    fn registration_fee_changed_event(
+        &self,
+        #[indexed] version: u8,
+        #[indexed] previous_fee: &BigUint,
+        new_fee: &BigUint,
+    );
+
+    #[event("auditorAdmitted")]
+    fn auditor_admitted_event(&self, #[indexed] version: u8, #[indexed] auditor: &ManagedAddress, timestamp: u64);
+
+    #[event("auditorRemoved")]
+    fn auditor_removed_event(&self, #[indexed] version: u8, #[indexed] auditor: &ManagedAddress, timestamp: u64);
+}
@@ -0,0 +1,266 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{events, indexes, moderation, rewards, storage};
+
+// Proposals are voted on for three days and can be executed two days after voting ends
+pub const PROPOSAL_VOTING_PERIOD: u64 = 259_200;
+pub const PROPOSAL_TIMELOCK: u64 = 172_800;
+pub const PROPOSAL_QUORUM: u64 = 3;
+
+// Upper bound for the summary limit, to keep audits cheap to store
+pub const MAX_SUMMARY_LENGTH_LIMIT: usize = 4_096;
+
+// Ten years; keeps `timestamp + period` in `isAuditValid` far from overflow
+pub const MAX_AUDIT_VALIDITY_PERIOD: u64 = 315_360_000;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub enum ProposalAction<M: ManagedTypeApi> {
+    SetMaxSummaryLength(usize),
+    SetAuditValidityPeriod(u64),
+    SetAdmissionRequired(bool),
+    AdmitAuditor(ManagedAddress<M>),
+    RemoveAuditor(ManagedAddress<M>),
+    SetRegistrationFee(BigUint<M>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Active,
+    Defeated,
+    Queued,
+    Executable,
+    Executed,
+    Vetoed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Proposal<M: ManagedTypeApi> {
+    pub proposer: ManagedAddress<M>,
+    pub action: ProposalAction<M>,
+    pub description: ManagedBuffer<M>,
+    pub created_at: u64,
+    pub voting_end: u64,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub executed: bool,
+    pub vetoed: bool,
+}
+
+// Registry parameters changed by admitted auditors through timelocked proposals; the owner can veto
+#[multiversx_sc::module]
+pub trait GovernanceModule:
+    storage::StorageModule
+    + indexes::IndexesModule
+    + rewards::RewardsModule
+    + moderation::ModerationModule
+    + events::EventsModule
+{
+    #[endpoint]
+    fn propose(&self, action: ProposalAction<Self::Api>, description: ManagedBuffer) -> usize {
+        let caller = self.blockchain().get_caller();
+        self.require_admitted_auditor(&caller);
+        self.validate_action(&action);
+
+        let created_at = self.blockchain().get_block_timestamp();
+        let proposal = Proposal {
+            proposer: caller.clone(),
+            action,
+            description,
+            created_at,
+            voting_end: created_at + PROPOSAL_VOTING_PERIOD,
+            yes_votes: 0,
+            no_votes: 0,
+            executed: false,
+            vetoed: false,
+        };
+        let proposal_id = self.proposals().push(&proposal);
+
+        self.proposal_created_event(proposal_id, &caller, &proposal.action);
+
+        proposal_id
+    }
+
+    #[endpoint]
+    fn vote_proposal(&self, proposal_id: usize, approve: bool) {
+        let caller = self.blockchain().get_caller();
+        self.require_admitted_auditor(&caller);
+
+        let mut proposal = self.get_proposal(proposal_id);
+        require!(
+            self.blockchain().get_block_timestamp() < proposal.voting_end,
+            "Voting has ended"
+        );
+        require!(
+            !self.proposal_voted(proposal_id, &caller).get(),
+            "Already voted"
+        );
+
+        if approve {
+            proposal.yes_votes += 1;
+        } else {
+            proposal.no_votes += 1;
+        }
+        self.proposals().set(proposal_id, &proposal);
+        self.proposal_voted(proposal_id, &caller).set(true);
+
+        self.proposal_voted_event(proposal_id, &caller, approve);
+    }
+
+    // Anyone can execute a passed proposal once its timelock has elapsed
+    #[endpoint]
+    fn execute_proposal(&self, proposal_id: usize) {
+        require!(
+            self.get_proposal_status(proposal_id) == ProposalStatus::Executable,
+            "Proposal is not executable"
+        );
+
+        let mut proposal = self.get_proposal(proposal_id);
+        match &proposal.action {
+            ProposalAction::SetMaxSummaryLength(max_summary_length) => {
+                self.max_summary_length().set(max_summary_length);
+            },
+            ProposalAction::SetAuditValidityPeriod(validity_period) => {
+                self.audit_validity_period().set(validity_period);
+            },
+            ProposalAction::SetAdmissionRequired(admission_required) => {
+                self.admission_required().set(admission_required);
+            },
+            ProposalAction::AdmitAuditor(auditor) => {
+                self.admit_auditor(auditor);
+            },
+            ProposalAction::RemoveAuditor(auditor) => {
+                self.remove_auditor(auditor);
+            },
+            ProposalAction::SetRegistrationFee(fee) => {
+                self.change_registration_fee(fee);
+            },
+        }
+
+        proposal.executed = true;
+        self.proposals().set(proposal_id, &proposal);
+
+        self.proposal_executed_event(proposal_id, &proposal.action);
+    }
+
+    // Lets the owner stop a proposal at any point before it is executed
+    #[endpoint]
+    fn veto_proposal(&self, proposal_id: usize) {
+        require!(
+            self.blockchain().get_caller() == self.owner().get(),
+            "Only owner can veto proposals"
+        );
+
+        let mut proposal = self.get_proposal(proposal_id);
+        require!(!proposal.executed, "Proposal already executed");
+        require!(!proposal.vetoed, "Proposal already vetoed");
+
+        proposal.vetoed = true;
+        self.proposals().set(proposal_id, &proposal);
+
+        self.proposal_vetoed_event(proposal_id);
+    }
+
+    // Views
+    #[view(getProposal)]
+    fn get_proposal(&self, proposal_id: usize) -> Proposal<Self::Api> {
+        let proposals_mapper = self.proposals();
+        require!(
+            proposal_id >= 1 && proposal_id <= proposals_mapper.len(),
+            "Proposal not found"
+        );
+
+        proposals_mapper.get(proposal_id)
+    }
+
+    #[view(getProposalCount)]
+    fn get_proposal_count(&self) -> usize {
+        self.proposals().len()
+    }
+
+    #[view(getProposalStatus)]
+    fn get_proposal_status(&self, proposal_id: usize) -> ProposalStatus {
+        let proposal = self.get_proposal(proposal_id);
+        let current_timestamp = self.blockchain().get_block_timestamp();
+
+        if proposal.executed {
+            ProposalStatus::Executed
+        } else if proposal.vetoed {
+            ProposalStatus::Vetoed
+        } else if current_timestamp < proposal.voting_end {
+            ProposalStatus::Active
+        } else if proposal.yes_votes <= proposal.no_votes || proposal.yes_votes < PROPOSAL_QUORUM {
+            ProposalStatus::Defeated
+        } else if current_timestamp < proposal.voting_end + PROPOSAL_TIMELOCK {
+            ProposalStatus::Queued
+        } else {
+            ProposalStatus::Executable
+        }
+    }
+
+    #[view(hasVotedOnProposal)]
+    fn has_voted_on_proposal(&self, proposal_id: usize, voter: ManagedAddress) -> bool {
+        self.proposal_voted(proposal_id, &voter).get()
+    }
+
+    // Internal helpers
+    // Registering an audit is permissionless, so only auditors admitted by the owner or a proposal vote
+    fn require_admitted_auditor(&self, address: &ManagedAddress) {
+        require!(
+            self.admitted_auditors().contains(address),
+            "Only admitted auditors can govern"
+        );
+        self.require_not_blocked(address);
+    }
+
+    fn validate_action(&self, action: &ProposalAction<Self::Api>) {
+        match action {
+            ProposalAction::SetMaxSummaryLength(max_summary_length) => require!(
+                *max_summary_length > 0 && *max_summary_length <= MAX_SUMMARY_LENGTH_LIMIT,
+                "Summary limit out of range"
+            ),
+            ProposalAction::SetAuditValidityPeriod(validity_period) => require!(
+                *validity_period <= MAX_AUDIT_VALIDITY_PERIOD,
+                "Validity period out of range"
+            ),
+            _ => {},
+        }
+    }
+
+    // Storage mappings
+    #[storage_mapper("proposals")]
+    fn proposals(&self) -> VecMapper<Proposal<Self::Api>>;
+
+    #[storage_mapper("proposalVoted")]
+    fn proposal_voted(&self, proposal_id: usize, voter: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    // Events
+    #[event("proposalCreated")]
+    fn proposal_created_event(
+        &self,
+        #[indexed] proposal_id: usize,
+        #[indexed] proposer: &ManagedAddress,
+        action: &ProposalAction<Self::Api>,
+    );
+
+    #[event("proposalVoted")]
+    fn proposal_voted_event(
+        &self,
+        #[indexed] proposal_id: usize,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] approve: bool,
+    );
+
+    #[event("proposalVetoed")]
+    fn proposal_vetoed_event(&self, #[indexed] proposal_id: usize);
+
+    #[event("proposalExecuted")]
+    fn proposal_executed_event(
+        &self,
+        #[indexed] proposal_id: usize,
+        action: &ProposalAction<Self::Api>,
+    );
+}
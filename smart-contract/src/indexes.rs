@@ -0,0 +1,198 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{storage, Audit, AuditLocation, IndexedAudit};
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+// Bounds the number of day buckets a single time-range query scans
+pub const MAX_QUERY_DAYS: u64 = 366;
+
+// Secondary indexes by registration day, by star rating and in global registration order
+#[multiversx_sc::module]
+pub trait IndexesModule: storage::StorageModule {
+    // Visible audits registered in [from_timestamp, to_timestamp], oldest first
+    #[view(getAuditsBetween)]
+    fn get_audits_between(
+        &self,
+        from_timestamp: u64,
+        to_timestamp: u64,
+        start_index: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<IndexedAudit<Self::Api>> {
+        require!(from_timestamp <= to_timestamp, "Invalid time range");
+        let first_day = from_timestamp / SECONDS_PER_DAY;
+        let last_day = to_timestamp / SECONDS_PER_DAY;
+        require!(last_day - first_day < MAX_QUERY_DAYS, "Time range too large");
+
+        let mut result = MultiValueEncoded::new();
+        let mut skipped = 0;
+        let mut returned = 0;
+        for day in first_day..=last_day {
+            for location in self.audits_by_day(day).iter() {
+                if returned == limit {
+                    return result;
+                }
+
+                let header = self.audit_headers(&location.contract_hash).get(location.audit_index);
+                if header.timestamp < from_timestamp
+                    || header.timestamp > to_timestamp
+                    || !self.is_audit_visible(&location.contract_hash, location.audit_index, &header)
+                {
+                    continue;
+                }
+                if skipped < start_index {
+                    skipped += 1;
+                    continue;
+                }
+
+                let audit = self.load_audit(&location.contract_hash, location.audit_index);
+                result.push((location.contract_hash, location.audit_index, audit).into());
+                returned += 1;
+            }
+        }
+
+        result
+    }
+
+    // Contracts whose latest visible audit has at least min_stars, best rated first
+    #[view(getContractsByMinStars)]
+    fn get_contracts_by_min_stars(
+        &self,
+        min_stars: u8,
+        start_index: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<MultiValue2<ManagedByteArray<Self::Api, 32>, u8>> {
+        require!(min_stars <= 5, "Stars must be between 0 and 5");
+
+        let mut result = MultiValueEncoded::new();
+        let mut skipped = 0;
+        let mut returned = 0;
+        for stars in (min_stars..=5).rev() {
+            let bucket = self.contracts_by_stars(stars);
+            let bucket_len = bucket.len();
+            if skipped + bucket_len <= start_index {
+                skipped += bucket_len;
+                continue;
+            }
+
+            // Set mapper indexes start from 1
+            for i in (start_index - skipped + 1)..=bucket_len {
+                if returned == limit {
+                    return result;
+                }
+                result.push((bucket.get_by_index(i), stars).into());
+                returned += 1;
+            }
+            skipped = start_index;
+        }
+
+        result
+    }
+
+    // Most recent visible audits across all contracts, newest first; start_index counts
+    // visible audits back from the newest
+    #[view(getRecentAudits)]
+    fn get_recent_audits(&self, start_index: usize, limit: usize) -> MultiValueEncoded<IndexedAudit<Self::Api>> {
+        let log_mapper = self.audit_log();
+        let total_entries = log_mapper.len();
+        require!(start_index < total_entries, "Start index out of bounds");
+
+        let mut result = MultiValueEncoded::new();
+        let mut skipped = 0;
+        let mut returned = 0;
+        // VecMapper indexes start from 1
+        for log_index in (1..=total_entries).rev() {
+            if returned == limit {
+                break;
+            }
+
+            let location = log_mapper.get(log_index);
+            let header = self.audit_headers(&location.contract_hash).get(location.audit_index);
+            if !self.is_audit_visible(&location.contract_hash, location.audit_index, &header) {
+                continue;
+            }
+            if skipped < start_index {
+                skipped += 1;
+                continue;
+            }
+
+            let audit = self.load_audit(&location.contract_hash, location.audit_index);
+            result.push((location.contract_hash, location.audit_index, audit).into());
+            returned += 1;
+        }
+
+        result
+    }
+
+    #[view(getTotalAuditLogEntries)]
+    fn get_total_audit_log_entries(&self) -> usize {
+        self.audit_log().len()
+    }
+
+    #[view(getTotalContractsByStars)]
+    fn get_total_contracts_by_stars(&self, stars: u8) -> usize {
+        self.contracts_by_stars(stars).len()
+    }
+
+    // Internal helpers
+    // Called for every newly stored audit; contracts are rated by their latest audit
+    fn index_audit(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize, audit: &Audit<Self::Api>) {
+        self.index_past_audit(contract_hash, audit_index, audit);
+        self.audit_log().push(&AuditLocation {
+            contract_hash: contract_hash.clone(),
+            audit_index,
+        });
+    }
+
+    // Migrated legacy audits are bucketed by their original day but kept out of the log,
+    // which lists audits newest first
+    fn index_past_audit(
+        &self,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        audit: &Audit<Self::Api>,
+    ) {
+        let location = AuditLocation {
+            contract_hash: contract_hash.clone(),
+            audit_index,
+        };
+        self.audits_by_day(audit.timestamp / SECONDS_PER_DAY).push(&location);
+        self.refresh_star_index(contract_hash);
+    }
+
+    // Rates a contract by its latest audit that is neither revoked nor hidden; called whenever
+    // an audit is stored, amended, revoked or moderated
+    fn refresh_star_index(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) {
+        let stars = if self.hidden_contract(contract_hash).get() {
+            None
+        } else {
+            let headers_mapper = self.audit_headers(contract_hash);
+            (1..=headers_mapper.len())
+                .rev()
+                .map(|audit_index| (audit_index, headers_mapper.get(audit_index)))
+                .find(|(audit_index, header)| !header.revoked && !self.hidden_audit(contract_hash, *audit_index).get())
+                .map(|(_, header)| header.stars)
+        };
+
+        for bucket_stars in 0..=5u8 {
+            if stars != Some(bucket_stars) {
+                self.contracts_by_stars(bucket_stars).swap_remove(contract_hash);
+            }
+        }
+        if let Some(stars) = stars {
+            self.contracts_by_stars(stars).insert(contract_hash.clone());
+        }
+    }
+
+    // Storage mappings
+    #[storage_mapper("auditsByDay")]
+    fn audits_by_day(&self, day: u64) -> VecMapper<AuditLocation<Self::Api>>;
+
+    // Append-only, in registration order
+    #[storage_mapper("auditLog")]
+    fn audit_log(&self) -> VecMapper<AuditLocation<Self::Api>>;
+
+    #[storage_mapper("contractsByStars")]
+    fn contracts_by_stars(&self, stars: u8) -> UnorderedSetMapper<ManagedByteArray<Self::Api, 32>>;
+}
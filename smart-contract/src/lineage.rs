@@ -0,0 +1,123 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{indexes, moderation, project_response, rewards, storage, watchlist, IndexedAudit};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct CodeVersion<M: ManagedTypeApi> {
+    pub code_hash: ManagedByteArray<M, 32>,
+    pub recorded_at: u64,
+}
+
+// Audit history of a deployed address across its code upgrades
+#[multiversx_sc::module]
+pub trait LineageModule:
+    storage::StorageModule
+    + indexes::IndexesModule
+    + rewards::RewardsModule
+    + moderation::ModerationModule
+    + watchlist::WatchlistModule
+    + project_response::ProjectResponseModule
+{
+    // The project owner of the current version, or a moderator, records each upgrade
+    #[endpoint]
+    fn record_upgrade(&self, sc_address: ManagedAddress, new_code_hash: ManagedByteArray<Self::Api, 32>) {
+        let lineage_mapper = self.contract_lineage(&sc_address);
+        require!(!lineage_mapper.is_empty(), "Contract address not linked");
+
+        let current_hash = lineage_mapper.get(lineage_mapper.len()).code_hash;
+        require!(new_code_hash != current_hash, "Code hash unchanged");
+
+        let caller = self.blockchain().get_caller();
+        let link_mapper = self.project_link(&current_hash);
+        let is_project_owner = !link_mapper.is_empty() && {
+            let link = link_mapper.get();
+            link.sc_address == sc_address && link.project_owner == caller
+        };
+        if !is_project_owner {
+            require!(
+                caller == self.owner().get() || self.moderators().contains(&caller),
+                "Only the project owner can record upgrades"
+            );
+        }
+
+        let recorded_at = self.blockchain().get_block_timestamp();
+        self.contract_lineage(&sc_address).push(&CodeVersion {
+            code_hash: new_code_hash.clone(),
+            recorded_at,
+        });
+
+        // The new version stays under the same project owner unless it was linked separately
+        if !link_mapper.is_empty() && self.project_link(&new_code_hash).is_empty() {
+            self.project_link(&new_code_hash).set(link_mapper.get());
+        }
+
+        self.contract_upgraded_event(&sc_address, &current_hash, &new_code_hash, recorded_at);
+    }
+
+    // Views
+    #[view(getContractLineage)]
+    fn get_contract_lineage(&self, sc_address: ManagedAddress) -> MultiValueEncoded<CodeVersion<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for version in self.contract_lineage(&sc_address).iter() {
+            result.push(version);
+        }
+
+        result
+    }
+
+    #[view(getLineageAudits)]
+    fn get_lineage_audits(&self, sc_address: ManagedAddress) -> MultiValueEncoded<IndexedAudit<Self::Api>> {
+        self.lineage_audits(&sc_address, false)
+    }
+
+    #[view(getVisibleLineageAudits)]
+    fn get_visible_lineage_audits(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> MultiValueEncoded<IndexedAudit<Self::Api>> {
+        self.lineage_audits(&sc_address, true)
+    }
+
+    // Internal helpers
+    // Versions seen more than once, e.g. after a rollback, are only listed the first time
+    fn lineage_audits(
+        &self,
+        sc_address: &ManagedAddress,
+        visible_only: bool,
+    ) -> MultiValueEncoded<IndexedAudit<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let lineage_mapper = self.contract_lineage(sc_address);
+        for (position, version) in lineage_mapper.iter().enumerate() {
+            let seen_before = lineage_mapper
+                .iter()
+                .take(position)
+                .any(|earlier| earlier.code_hash == version.code_hash);
+            if seen_before || (visible_only && self.hidden_contract(&version.code_hash).get()) {
+                continue;
+            }
+
+            for (position, header) in self.audit_headers(&version.code_hash).iter().enumerate() {
+                let audit_index = position + 1;
+                if visible_only && (header.revoked || self.hidden_audit(&version.code_hash, audit_index).get()) {
+                    continue;
+                }
+                let audit = self.load_audit(&version.code_hash, audit_index);
+                result.push((version.code_hash.clone(), audit_index, audit).into());
+            }
+        }
+
+        result
+    }
+
+    // Events
+    #[event("contractUpgraded")]
+    fn contract_upgraded_event(
+        &self,
+        #[indexed] sc_address: &ManagedAddress,
+        #[indexed] previous_code_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] new_code_hash: &ManagedByteArray<Self::Api, 32>,
+        recorded_at: u64,
+    );
+}
@@ -0,0 +1,258 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{indexes, rewards, storage};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ModerationReason {
+    Spam,
+    Abusive,
+    Plagiarism,
+    Misleading,
+    AppealAccepted,
+    Other,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ModerationActionKind {
+    HideAudit,
+    UnhideAudit,
+    HideContract,
+    UnhideContract,
+    BlockAuditor,
+    UnblockAuditor,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub enum ModerationTarget<M: ManagedTypeApi> {
+    Audit(ManagedByteArray<M, 32>, usize),
+    Contract(ManagedByteArray<M, 32>),
+    Auditor(ManagedAddress<M>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct ModerationLogEntry<M: ManagedTypeApi> {
+    pub moderator: ManagedAddress<M>,
+    pub action: ModerationActionKind,
+    pub target: ModerationTarget<M>,
+    pub reason: ModerationReason,
+    pub timestamp: u64,
+}
+
+// Moderator tools against spam and abusive audits
+#[multiversx_sc::module]
+pub trait ModerationModule: storage::StorageModule + indexes::IndexesModule + rewards::RewardsModule {
+    #[endpoint]
+    fn add_moderator(&self, moderator: ManagedAddress) {
+        self.require_owner();
+        self.moderators().insert(moderator);
+    }
+
+    #[endpoint]
+    fn remove_moderator(&self, moderator: ManagedAddress) {
+        self.require_owner();
+        self.moderators().swap_remove(&moderator);
+    }
+
+    #[endpoint]
+    fn hide_audit(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        reason: ModerationReason,
+    ) {
+        self.require_moderator();
+        require!(
+            audit_index >= 1 && audit_index <= self.audit_count(&contract_hash),
+            "Audit not found"
+        );
+        require!(!self.hidden_audit(&contract_hash, audit_index).get(), "Audit already hidden");
+
+        let earned_rewards = self.audit_earns_rewards(&contract_hash, audit_index);
+        self.hidden_audit(&contract_hash, audit_index).set(true);
+        if earned_rewards {
+            self.remove_reward_weight(&contract_hash, audit_index);
+        }
+        self.refresh_star_index(&contract_hash);
+        self.log_moderation(
+            ModerationActionKind::HideAudit,
+            ModerationTarget::Audit(contract_hash, audit_index),
+            reason,
+        );
+    }
+
+    #[endpoint]
+    fn unhide_audit(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        reason: ModerationReason,
+    ) {
+        self.require_moderator();
+        require!(self.hidden_audit(&contract_hash, audit_index).get(), "Audit is not hidden");
+
+        self.hidden_audit(&contract_hash, audit_index).clear();
+        if self.audit_earns_rewards(&contract_hash, audit_index) {
+            self.add_reward_weight(&contract_hash, audit_index);
+        }
+        self.refresh_star_index(&contract_hash);
+        self.log_moderation(
+            ModerationActionKind::UnhideAudit,
+            ModerationTarget::Audit(contract_hash, audit_index),
+            reason,
+        );
+    }
+
+    #[endpoint]
+    fn hide_contract(&self, contract_hash: ManagedByteArray<Self::Api, 32>, reason: ModerationReason) {
+        self.require_moderator();
+        require!(self.hash_exists(&contract_hash).get(), "No audits found for this contract");
+        require!(!self.hidden_contract(&contract_hash).get(), "Contract already hidden");
+
+        self.hidden_contract(&contract_hash).set(true);
+        self.refresh_star_index(&contract_hash);
+        self.log_moderation(
+            ModerationActionKind::HideContract,
+            ModerationTarget::Contract(contract_hash),
+            reason,
+        );
+    }
+
+    #[endpoint]
+    fn unhide_contract(&self, contract_hash: ManagedByteArray<Self::Api, 32>, reason: ModerationReason) {
+        self.require_moderator();
+        require!(self.hidden_contract(&contract_hash).get(), "Contract is not hidden");
+
+        self.hidden_contract(&contract_hash).clear();
+        self.refresh_star_index(&contract_hash);
+        self.log_moderation(
+            ModerationActionKind::UnhideContract,
+            ModerationTarget::Contract(contract_hash),
+            reason,
+        );
+    }
+
+    // Blocked auditors cannot register new audits or earn rewards; existing audits stay until hidden
+    #[endpoint]
+    fn block_auditor(&self, auditor: ManagedAddress, reason: ModerationReason) {
+        self.require_moderator();
+        // Their reward shares stop earning while blocked
+        self.update_reward_weight(&auditor, || {
+            require!(self.blocked_auditors().insert(auditor.clone()), "Auditor already blocked");
+        });
+
+        self.log_moderation(
+            ModerationActionKind::BlockAuditor,
+            ModerationTarget::Auditor(auditor),
+            reason,
+        );
+    }
+
+    #[endpoint]
+    fn unblock_auditor(&self, auditor: ManagedAddress, reason: ModerationReason) {
+        self.require_moderator();
+        self.update_reward_weight(&auditor, || {
+            require!(self.blocked_auditors().swap_remove(&auditor), "Auditor is not blocked");
+        });
+
+        self.log_moderation(
+            ModerationActionKind::UnblockAuditor,
+            ModerationTarget::Auditor(auditor),
+            reason,
+        );
+    }
+
+    // Views
+    #[view(isAuditHidden)]
+    fn is_audit_hidden(&self, contract_hash: ManagedByteArray<Self::Api, 32>, audit_index: usize) -> bool {
+        self.hidden_contract(&contract_hash).get() || self.hidden_audit(&contract_hash, audit_index).get()
+    }
+
+    #[view(getModerationLogLength)]
+    fn get_moderation_log_length(&self) -> usize {
+        self.moderation_log().len()
+    }
+
+    #[view(getModerationLog)]
+    fn get_moderation_log(
+        &self,
+        start_index: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<ModerationLogEntry<Self::Api>> {
+        let log_mapper = self.moderation_log();
+        let total_entries = log_mapper.len();
+        require!(start_index < total_entries, "Start index out of bounds");
+
+        let actual_limit = core::cmp::min(limit, total_entries - start_index);
+        let mut result = MultiValueEncoded::new();
+
+        // VecMapper indexes start from 1
+        for i in 1..=actual_limit {
+            result.push(log_mapper.get(start_index + i));
+        }
+
+        result
+    }
+
+    // Internal helpers
+    fn require_owner(&self) {
+        require!(
+            self.blockchain().get_caller() == self.owner().get(),
+            "Only owner can manage moderators"
+        );
+    }
+
+    fn require_moderator(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.owner().get() || self.moderators().contains(&caller),
+            "Only moderators can moderate"
+        );
+    }
+
+    fn require_not_blocked(&self, auditor: &ManagedAddress) {
+        require!(!self.blocked_auditors().contains(auditor), "Auditor is blocked");
+    }
+
+    fn log_moderation(
+        &self,
+        action: ModerationActionKind,
+        target: ModerationTarget<Self::Api>,
+        reason: ModerationReason,
+    ) {
+        let moderator = self.blockchain().get_caller();
+        let entry = ModerationLogEntry {
+            moderator: moderator.clone(),
+            action,
+            target: target.clone(),
+            reason,
+            timestamp: self.blockchain().get_block_timestamp(),
+        };
+        let log_index = self.moderation_log().push(&entry);
+
+        self.moderation_action_event(&moderator, action, log_index, &target, reason);
+    }
+
+    // Storage mappings
+    #[view(getModerators)]
+    #[storage_mapper("moderators")]
+    fn moderators(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("moderationLog")]
+    fn moderation_log(&self) -> VecMapper<ModerationLogEntry<Self::Api>>;
+
+    // Events
+    #[event("moderationAction")]
+    fn moderation_action_event(
+        &self,
+        #[indexed] moderator: &ManagedAddress,
+        #[indexed] action: ModerationActionKind,
+        #[indexed] log_index: usize,
+        #[indexed] target: &ModerationTarget<Self::Api>,
+        reason: ModerationReason,
+    );
+}
@@ -0,0 +1,160 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{indexes, moderation, rewards, storage, team_audit};
+
+pub const MAX_REVIEW_COMMENT_LENGTH: usize = 140;
+
+// Reputation weights: each audited contract earns one point, reviews move it by two
+pub const REVIEW_REPUTATION_WEIGHT: u64 = 2;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReviewVerdict {
+    Endorse,
+    Contest,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct PeerReview<M: ManagedTypeApi> {
+    pub reviewer: ManagedAddress<M>,
+    pub verdict: ReviewVerdict,
+    pub comment: ManagedBuffer<M>,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct AuditorReputation {
+    pub audited_contracts: u64,
+    pub endorsements_received: u64,
+    pub contests_received: u64,
+    pub score: u64,
+}
+
+// Second opinions from registered auditors on individual audits
+#[multiversx_sc::module]
+pub trait PeerReviewModule:
+    storage::StorageModule
+    + indexes::IndexesModule
+    + rewards::RewardsModule
+    + moderation::ModerationModule
+    + team_audit::TeamAuditModule
+{
+    #[endpoint]
+    fn review_audit(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        verdict: ReviewVerdict,
+        comment: ManagedBuffer,
+    ) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.auditor_history(&caller).is_empty(),
+            "Only registered auditors can review"
+        );
+        self.require_not_blocked(&caller);
+        require!(comment.len() <= MAX_REVIEW_COMMENT_LENGTH, "Comment too long");
+
+        let mut header = self.audit_header(&contract_hash, audit_index);
+        require!(
+            !self.is_auditor_of(&header, &caller)
+                && !self
+                    .audit_co_signers(&contract_hash, audit_index)
+                    .get()
+                    .iter()
+                    .any(|co_signer| *co_signer == caller),
+            "Cannot review your own audit"
+        );
+        let auditor = self.auditor_address(header.auditor_id);
+        require!(
+            !self.has_reviewed(&contract_hash, audit_index, &caller).get(),
+            "Audit already reviewed"
+        );
+
+        match verdict {
+            ReviewVerdict::Endorse => {
+                header.endorsements += 1;
+                self.endorsements_received(&auditor).update(|count| *count += 1);
+            },
+            ReviewVerdict::Contest => {
+                header.contests += 1;
+                self.contests_received(&auditor).update(|count| *count += 1);
+            },
+        }
+        self.set_audit_header(&contract_hash, audit_index, &header);
+
+        self.has_reviewed(&contract_hash, audit_index, &caller).set(true);
+        self.audit_reviews(&contract_hash, audit_index).push(&PeerReview {
+            reviewer: caller.clone(),
+            verdict,
+            comment: comment.clone(),
+            timestamp: self.blockchain().get_block_timestamp(),
+        });
+
+        self.audit_reviewed_event(&contract_hash, audit_index, &caller, verdict, &comment);
+    }
+
+    #[view(getAuditReviews)]
+    fn get_audit_reviews(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+    ) -> MultiValueEncoded<PeerReview<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for review in self.audit_reviews(&contract_hash, audit_index).iter() {
+            result.push(review);
+        }
+
+        result
+    }
+
+    #[view(getAuditorReputation)]
+    fn get_auditor_reputation(&self, auditor: ManagedAddress) -> AuditorReputation {
+        let audited_contracts = self.auditor_history(&auditor).len() as u64;
+        let endorsements_received = self.endorsements_received(&auditor).get();
+        let contests_received = self.contests_received(&auditor).get();
+
+        let score = (audited_contracts + endorsements_received * REVIEW_REPUTATION_WEIGHT)
+            .saturating_sub(contests_received * REVIEW_REPUTATION_WEIGHT);
+
+        AuditorReputation {
+            audited_contracts,
+            endorsements_received,
+            contests_received,
+            score,
+        }
+    }
+
+    // Storage mappings
+    #[storage_mapper("auditReviews")]
+    fn audit_reviews(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize)
+        -> VecMapper<PeerReview<Self::Api>>;
+
+    #[storage_mapper("hasReviewed")]
+    fn has_reviewed(
+        &self,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        reviewer: &ManagedAddress,
+    ) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("endorsementsReceived")]
+    fn endorsements_received(&self, auditor: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("contestsReceived")]
+    fn contests_received(&self, auditor: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Events
+    #[event("auditReviewed")]
+    fn audit_reviewed_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] audit_index: usize,
+        #[indexed] reviewer: &ManagedAddress,
+        #[indexed] verdict: ReviewVerdict,
+        comment: &ManagedBuffer,
+    );
+}
@@ -0,0 +1,269 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    lineage::CodeVersion,
+    indexes, moderation, rewards, storage,
+    watchlist::{self, WatchActivity},
+    Audit,
+};
+
+pub const MAX_RESPONSE_COMMENT_LENGTH: usize = 280;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ResponseStatus {
+    NoResponse,
+    Acknowledged,
+    Disputed,
+    Fixed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct ProjectLink<M: ManagedTypeApi> {
+    pub sc_address: ManagedAddress<M>,
+    pub project_owner: ManagedAddress<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct ProjectResponse<M: ManagedTypeApi> {
+    pub responder: ManagedAddress<M>,
+    pub status: ResponseStatus,
+    pub fixed_code_hash: Option<ManagedByteArray<M, 32>>,
+    pub comment: ManagedBuffer<M>,
+    pub timestamp: u64,
+}
+
+// Official responses from the owners of audited contracts
+#[multiversx_sc::module]
+pub trait ProjectResponseModule:
+    storage::StorageModule
+    + indexes::IndexesModule
+    + rewards::RewardsModule
+    + moderation::ModerationModule
+    + watchlist::WatchlistModule
+{
+    // Contracts cannot read another account's owner, so moderators attest the
+    // on-chain owner of the deployed SC before linking it to a contract hash
+    #[endpoint]
+    fn link_project(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        sc_address: ManagedAddress,
+        project_owner: ManagedAddress,
+    ) {
+        self.require_moderator();
+        require!(self.hash_exists(&contract_hash).get(), "No audits found for this contract");
+        require!(
+            self.blockchain().is_smart_contract(&sc_address),
+            "Address is not a smart contract"
+        );
+
+        // The first hash linked to an address starts its lineage
+        if self.contract_lineage(&sc_address).is_empty() {
+            self.contract_lineage(&sc_address).push(&CodeVersion {
+                code_hash: contract_hash.clone(),
+                recorded_at: self.blockchain().get_block_timestamp(),
+            });
+        }
+
+        self.project_link(&contract_hash).set(ProjectLink {
+            sc_address: sc_address.clone(),
+            project_owner: project_owner.clone(),
+        });
+
+        self.project_linked_event(&contract_hash, &sc_address, &project_owner);
+    }
+
+    #[endpoint]
+    fn unlink_project(&self, contract_hash: ManagedByteArray<Self::Api, 32>) {
+        self.require_moderator();
+        require!(!self.project_link(&contract_hash).is_empty(), "Project not linked");
+
+        self.project_link(&contract_hash).clear();
+
+        self.project_unlinked_event(&contract_hash);
+    }
+
+    // A later response replaces the previous one, e.g. acknowledged and then fixed
+    #[endpoint]
+    fn respond_to_audit(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        status: ResponseStatus,
+        comment: ManagedBuffer,
+        fixed_code_hash: OptionalValue<ManagedByteArray<Self::Api, 32>>,
+    ) {
+        let link_mapper = self.project_link(&contract_hash);
+        require!(!link_mapper.is_empty(), "Project not linked");
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == link_mapper.get().project_owner,
+            "Only the project owner can respond"
+        );
+        require!(status != ResponseStatus::NoResponse, "Invalid response");
+        let fixed_code_hash = fixed_code_hash.into_option();
+        if status == ResponseStatus::Fixed {
+            require!(
+                fixed_code_hash.as_ref().is_some_and(|fixed_hash| *fixed_hash != contract_hash),
+                "Fix must use a new code hash"
+            );
+        } else {
+            require!(fixed_code_hash.is_none(), "Only fixes have a code hash");
+        }
+        require!(comment.len() <= MAX_RESPONSE_COMMENT_LENGTH, "Comment too long");
+
+        let mut header = self.audit_header(&contract_hash, audit_index);
+        header.project_response = status;
+        self.set_audit_header(&contract_hash, audit_index, &header);
+
+        self.audit_response(&contract_hash, audit_index).set(ProjectResponse {
+            responder: caller.clone(),
+            status,
+            fixed_code_hash,
+            comment: comment.clone(),
+            timestamp: self.blockchain().get_block_timestamp(),
+        });
+
+        self.audit_responded_event(&contract_hash, audit_index, &caller, status, &comment);
+        if status == ResponseStatus::Disputed {
+            self.notify_watchers(&contract_hash, audit_index, WatchActivity::Dispute);
+        }
+    }
+
+    // Views
+    #[view(getProjectLink)]
+    fn get_project_link(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+    ) -> OptionalValue<ProjectLink<Self::Api>> {
+        let link_mapper = self.project_link(&contract_hash);
+        if link_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(link_mapper.get())
+        }
+    }
+
+    #[view(getAuditResponse)]
+    fn get_audit_response(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+    ) -> OptionalValue<ProjectResponse<Self::Api>> {
+        let response_mapper = self.audit_response(&contract_hash, audit_index);
+        if response_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(response_mapper.get())
+        }
+    }
+
+    // Audits of the code hash the project reported as the fix for this audit
+    #[view(getRemediationAudits)]
+    fn get_remediation_audits(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+    ) -> MultiValueEncoded<Audit<Self::Api>> {
+        self.remediation_audits(&contract_hash, audit_index, false)
+    }
+
+    // Same as getRemediationAudits, skipping revoked and hidden audits of the fixed code hash
+    #[view(getVisibleRemediationAudits)]
+    fn get_visible_remediation_audits(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+    ) -> MultiValueEncoded<Audit<Self::Api>> {
+        self.remediation_audits(&contract_hash, audit_index, true)
+    }
+
+    // Internal helpers
+    fn remediation_audits(
+        &self,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        visible_only: bool,
+    ) -> MultiValueEncoded<Audit<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let response_mapper = self.audit_response(contract_hash, audit_index);
+        if response_mapper.is_empty() {
+            return result;
+        }
+
+        let Some(fixed_hash) = response_mapper.get().fixed_code_hash else {
+            return result;
+        };
+        if visible_only && self.hidden_contract(&fixed_hash).get() {
+            return result;
+        }
+
+        for (position, header) in self.audit_headers(&fixed_hash).iter().enumerate() {
+            let fixed_index = position + 1;
+            if visible_only && (header.revoked || self.hidden_audit(&fixed_hash, fixed_index).get()) {
+                continue;
+            }
+            result.push(self.load_audit(&fixed_hash, fixed_index));
+        }
+
+        result
+    }
+
+    // Whether the auditor is the linked project owner or the deployed contract itself
+    fn check_self_audit(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, auditor: &ManagedAddress) -> bool {
+        let link_mapper = self.project_link(contract_hash);
+        if link_mapper.is_empty() {
+            return false;
+        }
+
+        let link = link_mapper.get();
+        let self_attested = *auditor == link.project_owner || *auditor == link.sc_address;
+        if self_attested {
+            require!(!self.reject_self_audits().get(), "Self-audits are not allowed");
+        }
+
+        self_attested
+    }
+
+    // Storage mappings
+    #[storage_mapper("projectLink")]
+    fn project_link(&self, contract_hash: &ManagedByteArray<Self::Api, 32>)
+        -> SingleValueMapper<ProjectLink<Self::Api>>;
+
+    #[storage_mapper("auditResponse")]
+    fn audit_response(
+        &self,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+    ) -> SingleValueMapper<ProjectResponse<Self::Api>>;
+
+    #[view(isRejectingSelfAudits)]
+    #[storage_mapper("rejectSelfAudits")]
+    fn reject_self_audits(&self) -> SingleValueMapper<bool>;
+
+    // Events
+    #[event("projectLinked")]
+    fn project_linked_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] sc_address: &ManagedAddress,
+        #[indexed] project_owner: &ManagedAddress,
+    );
+
+    #[event("projectUnlinked")]
+    fn project_unlinked_event(&self, #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>);
+
+    #[event("auditResponded")]
+    fn audit_responded_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] audit_index: usize,
+        #[indexed] responder: &ManagedAddress,
+        #[indexed] status: ResponseStatus,
+        comment: &ManagedBuffer,
+    );
+}
@@ -0,0 +1,155 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::storage;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug, Default)]
+pub struct RateLimits {
+    // Zero disables the per-window quota
+    pub max_audits_per_window: u32,
+    pub window_duration: u64,
+    // Zero disables the cooldown between audits of the same contract
+    pub same_contract_cooldown: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug, Default)]
+pub struct AuditorWindow {
+    pub window_start: u64,
+    pub audit_count: u32,
+}
+
+// Per-auditor rate limiting, enforced with block timestamps
+#[multiversx_sc::module]
+pub trait RateLimitModule: storage::StorageModule {
+    // Remaining audits in the current window and the timestamp at which the quota resets
+    #[view(getRemainingQuota)]
+    fn get_remaining_quota(&self, auditor: ManagedAddress) -> MultiValue2<u32, u64> {
+        let limits = self.get_rate_limits();
+        if limits.max_audits_per_window == 0 {
+            return (u32::MAX, 0u64).into();
+        }
+
+        let window = self.current_window(&auditor, &limits);
+        let remaining = limits.max_audits_per_window.saturating_sub(window.audit_count);
+
+        (remaining, window.window_start + limits.window_duration).into()
+    }
+
+    #[view(getNextAllowedAuditTime)]
+    fn get_next_allowed_audit_time(
+        &self,
+        auditor: ManagedAddress,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+    ) -> u64 {
+        let last_audit_mapper = self.last_contract_audit_time(&auditor, &contract_hash);
+        if last_audit_mapper.is_empty() {
+            return 0;
+        }
+
+        last_audit_mapper.get() + self.get_rate_limits().same_contract_cooldown
+    }
+
+    #[view(getRateLimits)]
+    fn get_rate_limits(&self) -> RateLimits {
+        let limits_mapper = self.rate_limits();
+        if limits_mapper.is_empty() {
+            RateLimits::default()
+        } else {
+            limits_mapper.get()
+        }
+    }
+
+    // Internal helpers
+    // New limits are queued through the admin timelock
+    fn require_valid_rate_limits(&self, limits: &RateLimits) {
+        require!(
+            limits.max_audits_per_window == 0 || limits.window_duration > 0,
+            "Window duration cannot be zero"
+        );
+    }
+
+    fn change_rate_limits(&self, limits: &RateLimits) {
+        self.rate_limits().set(limits);
+        self.rate_limits_set_event(
+            limits.max_audits_per_window,
+            limits.window_duration,
+            limits.same_contract_cooldown,
+        );
+    }
+
+    fn consume_audit_quota(
+        &self,
+        auditor: &ManagedAddress,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+        timestamp: u64,
+    ) {
+        let limits = self.get_rate_limits();
+
+        let last_audit_mapper = self.last_contract_audit_time(auditor, contract_hash);
+        if limits.same_contract_cooldown > 0 && !last_audit_mapper.is_empty() {
+            require!(
+                timestamp >= last_audit_mapper.get() + limits.same_contract_cooldown,
+                "Contract audited too recently"
+            );
+        }
+        last_audit_mapper.set(timestamp);
+
+        if limits.max_audits_per_window > 0 {
+            let mut window = self.current_window(auditor, &limits);
+            require!(
+                window.audit_count < limits.max_audits_per_window,
+                "Audit quota exceeded"
+            );
+
+            window.audit_count += 1;
+            self.auditor_window(auditor).set(window);
+        }
+    }
+
+    // Starts a fresh window once the stored one has elapsed
+    fn current_window(&self, auditor: &ManagedAddress, limits: &RateLimits) -> AuditorWindow {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let window_mapper = self.auditor_window(auditor);
+        if window_mapper.is_empty() {
+            return AuditorWindow {
+                window_start: current_timestamp,
+                audit_count: 0,
+            };
+        }
+
+        let window = window_mapper.get();
+        if current_timestamp >= window.window_start + limits.window_duration {
+            AuditorWindow {
+                window_start: current_timestamp,
+                audit_count: 0,
+            }
+        } else {
+            window
+        }
+    }
+
+    // Storage mappings
+    #[storage_mapper("rateLimits")]
+    fn rate_limits(&self) -> SingleValueMapper<RateLimits>;
+
+    #[storage_mapper("auditorWindow")]
+    fn auditor_window(&self, auditor: &ManagedAddress) -> SingleValueMapper<AuditorWindow>;
+
+    #[storage_mapper("lastContractAuditTime")]
+    fn last_contract_audit_time(
+        &self,
+        auditor: &ManagedAddress,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+    ) -> SingleValueMapper<u64>;
+
+    // Events
+    #[event("rateLimitsSet")]
+    fn rate_limits_set_event(
+        &self,
+        #[indexed] max_audits_per_window: u32,
+        #[indexed] window_duration: u64,
+        #[indexed] same_contract_cooldown: u64,
+    );
+}
@@ -0,0 +1,157 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::storage;
+
+// Shares are expressed in basis points of each collected fee
+pub const MAX_AUDITOR_SHARE_BPS: u32 = 10_000;
+
+// Fixed-point precision of the per-audit reward accumulator
+pub const REWARD_PRECISION: u64 = 1_000_000_000_000_000_000;
+
+// Fee revenue shared between the treasury and auditors, pro rata to registered audits
+#[multiversx_sc::module]
+pub trait RewardsModule: storage::StorageModule {
+    #[endpoint]
+    fn claim_rewards(&self) {
+        let caller = self.blockchain().get_caller();
+        self.settle_rewards(&caller);
+
+        // Fractions of a unit stay accrued until they add up to a payable amount
+        let accrued = self.accrued_rewards(&caller).get();
+        let amount = &accrued / REWARD_PRECISION;
+        require!(amount > 0, "No rewards to claim");
+        self.accrued_rewards(&caller).set(accrued - &amount * REWARD_PRECISION);
+
+        self.total_unclaimed_rewards().update(|total| *total -= &amount);
+        self.unlock_funds(&EgldOrEsdtTokenIdentifier::egld(), &amount);
+        self.send().direct_egld(&caller, &amount);
+
+        self.rewards_claimed_event(&caller, &amount);
+    }
+
+    // Views
+    #[view(getClaimableRewards)]
+    fn get_claimable_rewards(&self, auditor: ManagedAddress) -> BigUint {
+        (self.accrued_rewards(&auditor).get() + self.pending_rewards(&auditor)) / REWARD_PRECISION
+    }
+
+    #[view(getTotalClaimableRewards)]
+    fn get_total_claimable_rewards(&self) -> BigUint {
+        self.total_unclaimed_rewards().get()
+    }
+
+    // Internal helpers
+    // Moves the auditors' share of a collected fee into the reward pool; the rest stays with the treasury
+    fn distribute_fee_revenue(&self, fee: &BigUint) {
+        let total_weight = self.total_reward_weight().get();
+        let share_bps = self.auditor_share_bps().get();
+        if total_weight == 0 || share_bps == 0 || *fee == 0 {
+            return;
+        }
+
+        let share = fee * share_bps / MAX_AUDITOR_SHARE_BPS;
+        // The division remainder is carried into the next fee instead of being stranded
+        let scaled_share = &share * REWARD_PRECISION + self.reward_remainder().get();
+        self.reward_per_audit()
+            .update(|reward| *reward += &scaled_share / total_weight);
+        self.reward_remainder().set(scaled_share % total_weight);
+        self.total_unclaimed_rewards().update(|total| *total += &share);
+        self.lock_funds(&EgldOrEsdtTokenIdentifier::egld(), &share);
+    }
+
+    // An audit that paid its registration fee gives its auditor one share of future fee revenue,
+    // held for as long as the audit is neither revoked nor hidden
+    fn audit_earns_rewards(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize) -> bool {
+        self.fee_paid_audit(contract_hash, audit_index).get()
+            && !self.audit_header(contract_hash, audit_index).revoked
+            && !self.hidden_audit(contract_hash, audit_index).get()
+    }
+
+    fn add_reward_weight(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize) {
+        let auditor = self.auditor_address(self.audit_header(contract_hash, audit_index).auditor_id);
+        self.update_reward_weight(&auditor, || {
+            self.auditor_reward_weight(&auditor).update(|weight| *weight += 1);
+        });
+    }
+
+    fn remove_reward_weight(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize) {
+        let auditor = self.auditor_address(self.audit_header(contract_hash, audit_index).auditor_id);
+        self.update_reward_weight(&auditor, || {
+            self.auditor_reward_weight(&auditor).update(|weight| *weight -= 1);
+        });
+    }
+
+    // Settles the auditor before `change` moves their weight or blocked status, then rebases the debt
+    fn update_reward_weight<F: FnOnce()>(&self, auditor: &ManagedAddress, change: F) {
+        self.settle_rewards(auditor);
+        let previous_weight = self.earning_weight(auditor);
+        change();
+        let weight = self.earning_weight(auditor);
+
+        self.total_reward_weight()
+            .update(|total| *total = *total + weight - previous_weight);
+        self.auditor_reward_debt(auditor)
+            .set(self.reward_per_audit().get() * weight);
+    }
+
+    // Blocked auditors keep their shares but earn nothing on them until unblocked
+    fn earning_weight(&self, auditor: &ManagedAddress) -> u64 {
+        if self.blocked_auditors().contains(auditor) {
+            0
+        } else {
+            self.auditor_reward_weight(auditor).get()
+        }
+    }
+
+    fn settle_rewards(&self, auditor: &ManagedAddress) {
+        let pending = self.pending_rewards(auditor);
+        if pending > 0 {
+            self.accrued_rewards(auditor).update(|accrued| *accrued += &pending);
+            self.auditor_reward_debt(auditor).update(|debt| *debt += &pending);
+        }
+    }
+
+    // Debts, accrued and pending rewards are kept in REWARD_PRECISION units so no rounding is lost
+    fn pending_rewards(&self, auditor: &ManagedAddress) -> BigUint {
+        let weight = self.earning_weight(auditor);
+        let earned = self.reward_per_audit().get() * weight;
+
+        earned - self.auditor_reward_debt(auditor).get()
+    }
+
+    // Storage mappings
+    #[view(getAuditorShare)]
+    #[storage_mapper("auditorShareBps")]
+    fn auditor_share_bps(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("rewardPerAudit")]
+    fn reward_per_audit(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("totalRewardWeight")]
+    fn total_reward_weight(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("rewardRemainder")]
+    fn reward_remainder(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("auditorRewardWeight")]
+    fn auditor_reward_weight(&self, auditor: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("auditorRewardDebt")]
+    fn auditor_reward_debt(&self, auditor: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("accruedRewards")]
+    fn accrued_rewards(&self, auditor: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    // Audits registered with a non-zero fee; free audits never earn a reward share
+    #[storage_mapper("feePaidAudit")]
+    fn fee_paid_audit(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize)
+        -> SingleValueMapper<bool>;
+
+    #[storage_mapper("totalUnclaimedRewards")]
+    fn total_unclaimed_rewards(&self) -> SingleValueMapper<BigUint>;
+
+    // Events
+    #[event("rewardsClaimed")]
+    fn rewards_claimed_event(&self, #[indexed] auditor: &ManagedAddress, amount: &BigUint);
+}
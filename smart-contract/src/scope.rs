@@ -0,0 +1,115 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::storage;
+
+// Size limits for scope metadata
+pub const MAX_REPOSITORY_URL_LENGTH: usize = 256;
+pub const MAX_COMPILER_VERSION_LENGTH: usize = 64;
+pub const MAX_FILE_PATH_LENGTH: usize = 256;
+pub const MAX_SCOPE_FILES: usize = 100;
+
+// Git commit ids are SHA-1 or SHA-256 digests
+pub const SHA1_COMMIT_LENGTH: usize = 20;
+pub const SHA256_COMMIT_LENGTH: usize = 32;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Debug)]
+pub struct ScopeFile<M: ManagedTypeApi> {
+    pub path: ManagedBuffer<M>,
+    pub file_hash: ManagedByteArray<M, 32>,
+}
+
+// What was actually reviewed in an audit
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct AuditScope<M: ManagedTypeApi> {
+    pub repository_url: ManagedBuffer<M>,
+    pub commit_hash: ManagedBuffer<M>,
+    pub compiler_version: ManagedBuffer<M>,
+    pub files: ManagedVec<M, ScopeFile<M>>,
+}
+
+// Optional scope metadata attached to individual audits
+#[multiversx_sc::module]
+pub trait ScopeModule: storage::StorageModule {
+    #[endpoint]
+    fn set_audit_scope(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        repository_url: ManagedBuffer,
+        commit_hash: ManagedBuffer,
+        compiler_version: ManagedBuffer,
+        files: MultiValueEncoded<MultiValue2<ManagedBuffer, ManagedByteArray<Self::Api, 32>>>,
+    ) {
+        let header = self.audit_header(&contract_hash, audit_index);
+        require!(
+            self.is_auditor_of(&header, &self.blockchain().get_caller()),
+            "Only the auditor can set the audit scope"
+        );
+
+        let scope_mapper = self.audit_scope(&contract_hash, audit_index);
+        require!(scope_mapper.is_empty(), "Audit scope already set");
+
+        require!(!repository_url.is_empty(), "Repository URL cannot be empty");
+        require!(
+            repository_url.len() <= MAX_REPOSITORY_URL_LENGTH,
+            "Repository URL too long"
+        );
+        require!(
+            commit_hash.len() == SHA1_COMMIT_LENGTH || commit_hash.len() == SHA256_COMMIT_LENGTH,
+            "Invalid commit hash"
+        );
+        require!(
+            compiler_version.len() <= MAX_COMPILER_VERSION_LENGTH,
+            "Compiler version too long"
+        );
+        require!(files.len() <= MAX_SCOPE_FILES, "Too many scope files");
+
+        let mut scope_files = ManagedVec::new();
+        for file in files.into_iter() {
+            let (path, file_hash) = file.into_tuple();
+            require!(!path.is_empty(), "File path cannot be empty");
+            require!(path.len() <= MAX_FILE_PATH_LENGTH, "File path too long");
+
+            scope_files.push(ScopeFile { path, file_hash });
+        }
+
+        scope_mapper.set(AuditScope {
+            repository_url,
+            commit_hash,
+            compiler_version,
+            files: scope_files,
+        });
+
+        self.audit_scope_set_event(&contract_hash, audit_index);
+    }
+
+    #[view(getAuditScope)]
+    fn get_audit_scope(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+    ) -> OptionalValue<AuditScope<Self::Api>> {
+        let scope_mapper = self.audit_scope(&contract_hash, audit_index);
+        if scope_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(scope_mapper.get())
+        }
+    }
+
+    // Storage mappings
+    #[storage_mapper("auditScope")]
+    fn audit_scope(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize)
+        -> SingleValueMapper<AuditScope<Self::Api>>;
+
+    // Events
+    #[event("auditScopeSet")]
+    fn audit_scope_set_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] audit_index: usize,
+    );
+}
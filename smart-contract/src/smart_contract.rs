@@ -3,6 +3,102 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub mod bounty;
+pub mod commitment;
+pub mod community_vote;
+pub mod coverage;
+pub mod events;
+pub mod governance;
+pub mod indexes;
+pub mod lineage;
+pub mod moderation;
+pub mod peer_review;
+pub mod project_response;
+pub mod rate_limit;
+pub mod rewards;
+pub mod scope;
+pub mod storage;
+pub mod tags;
+pub mod team_audit;
+pub mod timelock;
+pub mod watchlist;
+
+use commitment::{AuditCommitment, MAX_DISCLOSURE_DELAY};
+use events::{AuditEventData, EVENT_SCHEMA_VERSION};
+use project_response::ResponseStatus;
+use team_audit::{TeamAuditDraft, MAX_CO_AUDITORS};
+use watchlist::WatchActivity;
+
+// Maximum number of audits accepted by a single register_audits_batch call
+pub const MAX_BATCH_SIZE: usize = 50;
+
+// Summary limit used until governance changes it
+pub const DEFAULT_MAX_SUMMARY_LENGTH: usize = 500;
+
+// Chain used by the legacy endpoints, whose code hashes are stored unprefixed
+pub const DEFAULT_CHAIN_ID: &[u8] = b"multiversx";
+pub const MAX_CHAIN_ID_LENGTH: usize = 32;
+
+// How the 32-byte contract hash was computed
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HashAlgorithm {
+    MultiversXCodeHash,
+    EvmBytecodeKeccak256,
+    SourceHash,
+}
+
+// Chain and hashing scheme a registry key belongs to
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct ContractNamespace<M: ManagedTypeApi> {
+    pub chain_id: ManagedBuffer<M>,
+    pub hash_algorithm: HashAlgorithm,
+    pub contract_hash: ManagedByteArray<M, 32>,
+}
+
+pub const MAX_PROVENANCE_FIELD_LENGTH: usize = 64;
+
+// Who or what produced an audit; Unspecified is used by the legacy endpoints
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AuditKind {
+    Unspecified,
+    Human,
+    Ai,
+    Hybrid,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Debug)]
+pub struct AuditProvenance<M: ManagedTypeApi> {
+    pub kind: AuditKind,
+    pub model_id: ManagedBuffer<M>,
+    pub model_version: ManagedBuffer<M>,
+    pub prompt_template_hash: ManagedBuffer<M>,
+    pub tool_version: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> AuditProvenance<M> {
+    pub fn unspecified() -> Self {
+        AuditProvenance {
+            kind: AuditKind::Unspecified,
+            model_id: ManagedBuffer::new(),
+            model_version: ManagedBuffer::new(),
+            prompt_template_hash: ManagedBuffer::new(),
+            tool_version: ManagedBuffer::new(),
+        }
+    }
+
+    pub fn is_unspecified(&self) -> bool {
+        self.kind == AuditKind::Unspecified
+            && self.model_id.is_empty()
+            && self.model_version.is_empty()
+            && self.prompt_template_hash.is_empty()
+            && self.tool_version.is_empty()
+    }
+}
+
 // Define the Audit struct outside the trait
 // Adding the Debug trait for better interactor integration
 #[type_abi]
@@ -12,17 +108,102 @@ pub struct Audit<M: ManagedTypeApi> {
     pub summary: ManagedBuffer<M>,
     pub auditor: ManagedAddress<M>,
     pub timestamp: u64,
+    pub chain_id: ManagedBuffer<M>,
+    pub hash_algorithm: HashAlgorithm,
+    pub provenance: AuditProvenance<M>,
+    pub endorsements: u32,
+    pub contests: u32,
+    pub project_response: ResponseStatus,
+    // Registered by the owner of the audited contract
+    pub self_attested: bool,
+    // Zero until the auditor amends the audit
+    pub amended_at: u64,
+    pub revoked: bool,
+}
+
+// Audit as stored under `contractAudits` by the first deployed version; see migrate_legacy_audits
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct LegacyAudit<M: ManagedTypeApi> {
+    pub stars: u8,
+    pub summary: ManagedBuffer<M>,
+    pub auditor: ManagedAddress<M>,
+    pub timestamp: u64,
+}
+
+// Fixed-size part of an audit as stored; see StorageModule::load_audit for the full record
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct AuditHeader {
+    pub stars: u8,
+    pub auditor_id: u64,
+    pub timestamp: u64,
+    pub kind: AuditKind,
+    pub endorsements: u32,
+    pub contests: u32,
+    pub project_response: ResponseStatus,
+    pub self_attested: bool,
+    pub amended_at: u64,
+    pub revoked: bool,
+}
+
+// Latest audit of a contract, as returned by the list views
+pub type AuditListItem<M> = MultiValue6<
+    ManagedByteArray<M, 32>,
+    u8,
+    ManagedBuffer<M>,
+    ManagedAddress<M>,
+    u64,
+    bool,
+>;
+
+// Audit together with its contract hash and index
+pub type IndexedAudit<M> = MultiValue3<ManagedByteArray<M, 32>, usize, Audit<M>>;
+
+// Position of an audit in the registry
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct AuditLocation<M: ManagedTypeApi> {
+    pub contract_hash: ManagedByteArray<M, 32>,
+    pub audit_index: usize,
 }
 
 // MultiversX implementation of the AuditRegistry contract
 #[multiversx_sc::contract]
-pub trait AuditRegistry {
+pub trait AuditRegistry:
+    storage::StorageModule
+    + events::EventsModule
+    + bounty::BountyModule
+    + scope::ScopeModule
+    + moderation::ModerationModule
+    + rate_limit::RateLimitModule
+    + peer_review::PeerReviewModule
+    + community_vote::CommunityVoteModule
+    + commitment::CommitmentModule
+    + coverage::CoverageModule
+    + governance::GovernanceModule
+    + project_response::ProjectResponseModule
+    + lineage::LineageModule
+    + tags::TagsModule
+    + team_audit::TeamAuditModule
+    + indexes::IndexesModule
+    + rewards::RewardsModule
+    + timelock::TimelockModule
+    + watchlist::WatchlistModule
+{
     #[init]
     fn init(&self) {
-        self.owner().set(&self.blockchain().get_caller());
+        self.owner().set(self.blockchain().get_caller());
+        self.timelock_enabled().set(true);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {
+        self.consume_upgrade_approval();
     }
 
     // Endpoints
+    #[payable("EGLD")]
     #[endpoint]
     fn register_audit(
         &self,
@@ -30,93 +211,416 @@ pub trait AuditRegistry {
         stars: u8,
         summary: ManagedBuffer,
     ) {
-        require!(stars <= 5, "Stars must be between 0 and 5");
-        require!(!summary.is_empty(), "Summary cannot be empty");
-        require!(summary.len() <= 500, "Summary too long");
+        self.register_chain_audit(
+            ManagedBuffer::from(DEFAULT_CHAIN_ID),
+            HashAlgorithm::MultiversXCodeHash,
+            contract_hash,
+            stars,
+            summary,
+            OptionalValue::None,
+        );
+    }
+
+    // Registers an audit for a contract on any chain; the returned key is used by all other views
+    #[payable("EGLD")]
+    #[endpoint]
+    fn register_chain_audit(
+        &self,
+        chain_id: ManagedBuffer,
+        hash_algorithm: HashAlgorithm,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        stars: u8,
+        summary: ManagedBuffer,
+        provenance: OptionalValue<AuditProvenance<Self::Api>>,
+    ) -> ManagedByteArray<Self::Api, 32> {
+        self.validate_chain_id(&chain_id);
+        self.validate_audit(stars, &summary);
+
+        let provenance = provenance
+            .into_option()
+            .unwrap_or_else(AuditProvenance::unspecified);
+        self.validate_provenance(&provenance);
 
         let caller = self.blockchain().get_caller();
+        self.require_auditor_allowed(&caller);
+        let fee_paid = self.collect_registration_fee(1);
         let current_timestamp = self.blockchain().get_block_timestamp();
 
+        let namespace = ContractNamespace {
+            chain_id,
+            hash_algorithm,
+            contract_hash,
+        };
+        let contract_key = self.compute_contract_key(&namespace);
+        let self_attested = self.check_self_audit(&contract_key, &caller);
+
         let new_audit = Audit {
             stars,
             summary: summary.clone(),
             auditor: caller.clone(),
             timestamp: current_timestamp,
+            chain_id: namespace.chain_id.clone(),
+            hash_algorithm,
+            provenance,
+            endorsements: 0,
+            contests: 0,
+            project_response: ResponseStatus::NoResponse,
+            self_attested,
+            amended_at: 0,
+            revoked: false,
         };
+        self.store_audit(&contract_key, &namespace, &new_audit, fee_paid);
 
-        // Add to contractAudits
-        self.contract_audits(&contract_hash).push(&new_audit);
+        contract_key
+    }
 
-        // Add to allContractHashes if new
-        if !self.hash_exists(&contract_hash).get() {
-            self.hash_exists(&contract_hash).set(true);
-            self.all_contract_hashes().push(&contract_hash);
+    #[payable("EGLD")]
+    #[endpoint]
+    fn register_audits_batch(
+        &self,
+        audits: MultiValueEncoded<
+            MultiValue3<ManagedByteArray<Self::Api, 32>, u8, ManagedBuffer>
+        >,
+    ) {
+        let batch_size = audits.len();
+        require!(batch_size > 0, "Batch cannot be empty");
+        require!(batch_size <= MAX_BATCH_SIZE, "Batch too large");
+
+        // Validate every entry before writing anything
+        for entry in audits.clone().into_iter() {
+            let (_, stars, summary) = entry.into_tuple();
+            self.validate_audit(stars, &summary);
         }
 
-        // Check if this is the first audit by this auditor for this contract
-        let mut is_new_contract = true;
-        let auditor_history_mapper = self.auditor_history(&caller);
-        
-        for i in 0..auditor_history_mapper.len() {
-            let existing_hash = auditor_history_mapper.get(i);
-            if existing_hash == contract_hash {
-                is_new_contract = false;
-                break;
-            }
+        let caller = self.blockchain().get_caller();
+        self.require_auditor_allowed(&caller);
+        let fee_paid = self.collect_registration_fee(batch_size);
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let chain_id = ManagedBuffer::from(DEFAULT_CHAIN_ID);
+
+        for entry in audits.into_iter() {
+            let (contract_hash, stars, summary) = entry.into_tuple();
+            let namespace = ContractNamespace {
+                chain_id: chain_id.clone(),
+                hash_algorithm: HashAlgorithm::MultiversXCodeHash,
+                contract_hash: contract_hash.clone(),
+            };
+            let new_audit = Audit {
+                stars,
+                summary,
+                auditor: caller.clone(),
+                timestamp: current_timestamp,
+                chain_id: chain_id.clone(),
+                hash_algorithm: HashAlgorithm::MultiversXCodeHash,
+                provenance: AuditProvenance::unspecified(),
+                endorsements: 0,
+                contests: 0,
+                project_response: ResponseStatus::NoResponse,
+                self_attested: self.check_self_audit(&contract_hash, &caller),
+                amended_at: 0,
+                revoked: false,
+            };
+            self.store_audit(&contract_hash, &namespace, &new_audit, fee_paid);
         }
-        
-        if is_new_contract {
-            self.auditor_history(&caller).push(&contract_hash);
+
+        // Emit a single event for the whole batch
+        self.audit_batch_registered_event(&caller, batch_size, current_timestamp);
+    }
+
+    // Moves audits stored under the pre-header layout into the current one. Anyone can call it;
+    // registering a new audit on a legacy hash migrates it first, so the order is kept.
+    #[endpoint]
+    fn migrate_legacy_audits(&self, contract_hashes: MultiValueEncoded<ManagedByteArray<Self::Api, 32>>) {
+        require!(contract_hashes.len() <= MAX_BATCH_SIZE, "Batch too large");
+
+        for contract_hash in contract_hashes.into_iter() {
+            require!(
+                !self.legacy_contract_audits(&contract_hash).is_empty(),
+                "No legacy audits for this contract"
+            );
+            self.migrate_legacy_contract(&contract_hash);
         }
+    }
 
-        // Emit event
-        self.audit_registered_event(
+    #[endpoint]
+    fn amend_audit(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        stars: u8,
+        summary: ManagedBuffer,
+    ) {
+        self.validate_audit(stars, &summary);
+        let mut header = self.own_audit(&contract_hash, audit_index);
+        let caller = self.blockchain().get_caller();
+        self.require_not_blocked(&caller);
+        // The co-auditors signed the original findings, so the lead cannot change them alone
+        require!(
+            self.audit_co_signers(&contract_hash, audit_index).is_empty(),
+            "Team audits cannot be amended"
+        );
+        // A published commitment fixes the content disclosed at its disclosure time
+        require!(
+            self.published_commitment(&contract_hash, audit_index).is_empty(),
+            "Commit-reveal audits cannot be amended"
+        );
+        // Reviews, votes and project responses were given to the original findings
+        require!(
+            header.endorsements == 0
+                && header.contests == 0
+                && header.project_response == ResponseStatus::NoResponse
+                && self.audit_votes(&contract_hash, audit_index).is_empty(),
+            "Audit already has reviews, votes or a response"
+        );
+
+        header.stars = stars;
+        header.amended_at = self.blockchain().get_block_timestamp();
+        self.set_audit_header(&contract_hash, audit_index, &header);
+        self.audit_summary(&contract_hash, audit_index).set(&summary);
+        self.refresh_star_index(&contract_hash);
+        self.notify_watchers(&contract_hash, audit_index, WatchActivity::Amendment);
+
+        self.audit_amended_event(
+            EVENT_SCHEMA_VERSION,
             &contract_hash,
-            stars,
+            audit_index,
             &caller,
-            &summary,
+            stars,
+            &AuditEventData {
+                timestamp: header.amended_at,
+                summary,
+            },
+        );
+    }
+
+    // Revoked audits stay in storage but are never valid and are left out of the visible views
+    #[endpoint]
+    fn revoke_audit(&self, contract_hash: ManagedByteArray<Self::Api, 32>, audit_index: usize) {
+        let mut header = self.own_audit(&contract_hash, audit_index);
+        if self.audit_earns_rewards(&contract_hash, audit_index) {
+            self.remove_reward_weight(&contract_hash, audit_index);
+        }
+
+        header.revoked = true;
+        self.set_audit_header(&contract_hash, audit_index, &header);
+        self.refresh_star_index(&contract_hash);
+        self.notify_watchers(&contract_hash, audit_index, WatchActivity::Revocation);
+
+        self.audit_revoked_event(
+            EVENT_SCHEMA_VERSION,
+            &contract_hash,
+            audit_index,
+            &self.blockchain().get_caller(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    // The lead auditor drafts a team audit and pays the registration fee, which is held until it is final
+    #[payable("EGLD")]
+    #[endpoint]
+    fn propose_team_audit(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        stars: u8,
+        summary: ManagedBuffer,
+        co_auditors: MultiValueEncoded<ManagedAddress>,
+    ) -> u64 {
+        self.validate_audit(stars, &summary);
+        let caller = self.blockchain().get_caller();
+        self.require_auditor_allowed(&caller);
+
+        let co_auditors = co_auditors.to_vec();
+        require!(!co_auditors.is_empty(), "Team audit needs co-auditors");
+        require!(co_auditors.len() <= MAX_CO_AUDITORS, "Too many co-auditors");
+        for (position, co_auditor) in co_auditors.iter().enumerate() {
+            require!(*co_auditor != caller, "Lead cannot co-sign");
+            require!(
+                !co_auditors.iter().take(position).any(|earlier| *earlier == *co_auditor),
+                "Duplicate co-auditor"
+            );
+        }
+
+        let fee = self.call_value().egld().clone_value();
+        require!(fee == self.registration_fee().get(), "Incorrect registration fee");
+        self.lock_funds(&EgldOrEsdtTokenIdentifier::egld(), &fee);
+
+        let draft_id = self.last_team_audit_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        self.team_audit_draft(draft_id).set(TeamAuditDraft {
+            lead: caller.clone(),
+            contract_hash: contract_hash.clone(),
+            stars,
+            summary,
+            co_auditors: co_auditors.clone(),
+            fee,
+            proposed_at: self.blockchain().get_block_timestamp(),
+        });
+
+        self.team_audit_proposed_event(draft_id, &contract_hash, &caller, &co_auditors);
+        draft_id
+    }
+
+    // The last co-auditor to sign registers the audit under the lead
+    #[endpoint]
+    fn sign_team_audit(&self, draft_id: u64) {
+        let draft = self.get_team_draft(draft_id);
+        let caller = self.blockchain().get_caller();
+        require!(
+            draft.co_auditors.iter().any(|co_auditor| *co_auditor == caller),
+            "Not a co-auditor of this audit"
+        );
+        self.require_auditor_allowed(&caller);
+        require!(self.team_audit_signatures(draft_id).insert(caller.clone()), "Already signed");
+        self.team_audit_signed_event(draft_id, &caller);
+
+        if self.team_audit_signatures(draft_id).len() == draft.co_auditors.len() {
+            self.finalize_team_audit(draft_id, draft);
+        }
+    }
+
+    #[endpoint]
+    fn cancel_team_audit(&self, draft_id: u64) {
+        let draft = self.get_team_draft(draft_id);
+        require!(
+            self.blockchain().get_caller() == draft.lead,
+            "Only the lead auditor can cancel"
+        );
+
+        self.team_audit_draft(draft_id).clear();
+        self.team_audit_signatures(draft_id).clear();
+        if draft.fee > 0 {
+            self.unlock_funds(&EgldOrEsdtTokenIdentifier::egld(), &draft.fee);
+            self.send().direct_egld(&draft.lead, &draft.fee);
+        }
+
+        self.team_audit_cancelled_event(draft_id, &draft.lead);
+    }
+
+    // Puts an audit on record as a hash of its content; it stays out of every list view until published
+    #[payable("EGLD")]
+    #[endpoint]
+    fn commit_audit(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        commitment: ManagedByteArray<Self::Api, 32>,
+        disclosure_time: u64,
+    ) -> u64 {
+        let caller = self.blockchain().get_caller();
+        self.require_auditor_allowed(&caller);
+        let committed_at = self.blockchain().get_block_timestamp();
+        require!(
+            disclosure_time > committed_at && disclosure_time <= committed_at + MAX_DISCLOSURE_DELAY,
+            "Invalid disclosure time"
         );
+        let fee_paid = self.collect_registration_fee(1);
+
+        let commitment_id = self.last_commitment_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        self.commitment_fee_paid(commitment_id).set(fee_paid);
+        self.audit_commitment(commitment_id).set(AuditCommitment {
+            auditor: caller.clone(),
+            contract_hash: contract_hash.clone(),
+            commitment: commitment.clone(),
+            committed_at,
+            disclosure_time,
+        });
+        self.pending_commitments(&contract_hash).insert(commitment_id);
+
+        self.audit_committed_event(commitment_id, &contract_hash, &caller, &commitment, disclosure_time);
+        commitment_id
     }
 
+    // Reveals a committed audit from the disclosure time on; the content must hash to the commitment
+    #[endpoint]
+    fn publish_audit(
+        &self,
+        commitment_id: u64,
+        stars: u8,
+        summary: ManagedBuffer,
+        salt: ManagedByteArray<Self::Api, 32>,
+    ) -> usize {
+        let commitment_mapper = self.audit_commitment(commitment_id);
+        require!(!commitment_mapper.is_empty(), "Commitment not found");
+        let commitment = commitment_mapper.get();
+
+        let caller = self.blockchain().get_caller();
+        require!(caller == commitment.auditor, "Only the auditor can publish");
+        self.require_auditor_allowed(&caller);
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            current_timestamp >= commitment.disclosure_time,
+            "Disclosure time not reached"
+        );
+        self.validate_audit(stars, &summary);
+        require!(
+            self.compute_audit_commitment(
+                caller.clone(),
+                commitment.contract_hash.clone(),
+                stars,
+                summary.clone(),
+                salt,
+            ) == commitment.commitment,
+            "Content does not match the commitment"
+        );
+
+        commitment_mapper.clear();
+        let fee_paid = self.commitment_fee_paid(commitment_id).take();
+        self.pending_commitments(&commitment.contract_hash).swap_remove(&commitment_id);
+
+        let namespace = ContractNamespace {
+            chain_id: ManagedBuffer::from(DEFAULT_CHAIN_ID),
+            hash_algorithm: HashAlgorithm::MultiversXCodeHash,
+            contract_hash: commitment.contract_hash.clone(),
+        };
+        let contract_key = self.compute_contract_key(&namespace);
+        let self_attested = self.check_self_audit(&contract_key, &caller);
+
+        let new_audit = Audit {
+            stars,
+            summary,
+            auditor: caller,
+            timestamp: current_timestamp,
+            chain_id: namespace.chain_id.clone(),
+            hash_algorithm: HashAlgorithm::MultiversXCodeHash,
+            provenance: AuditProvenance::unspecified(),
+            endorsements: 0,
+            contests: 0,
+            project_response: ResponseStatus::NoResponse,
+            self_attested,
+            amended_at: 0,
+            revoked: false,
+        };
+        let audit_index = self.store_audit(&contract_key, &namespace, &new_audit, fee_paid);
+        self.published_commitment(&contract_key, audit_index).set(&commitment);
+
+        self.audit_published_event(commitment_id, &contract_key, audit_index);
+        audit_index
+    }
+
+    // The optional kind filter is applied to the latest audit of each contract in the page
     #[view]
     fn get_all_audits(
         &self,
         start_index: usize,
         limit: usize,
-    ) -> MultiValueEncoded<
-        MultiValue5<
-            ManagedByteArray<Self::Api, 32>,
-            u8,
-            ManagedBuffer,
-            ManagedAddress,
-            u64,
-        >
-    > {
-        let total_hashes = self.all_contract_hashes().len();
-        require!(start_index < total_hashes, "Start index out of bounds");
-
-        let actual_limit = core::cmp::min(limit, total_hashes - start_index);
-        let mut result = MultiValueEncoded::new();
-
-        for i in 0..actual_limit {
-            let hash = self.all_contract_hashes().get(start_index + i);
-            let audits_mapper = self.contract_audits(&hash);
-            
-            if audits_mapper.len() > 0 {
-                let latest_audit = audits_mapper.get(audits_mapper.len() - 1);
-                result.push(
-                    (
-                        hash,
-                        latest_audit.stars,
-                        latest_audit.summary,
-                        latest_audit.auditor,
-                        latest_audit.timestamp
-                    ).into()
-                );
-            }
-        }
+        kind: OptionalValue<AuditKind>,
+    ) -> MultiValueEncoded<AuditListItem<Self::Api>> {
+        self.latest_audits_page(self.all_contract_hashes(), start_index, limit, kind, false)
+    }
 
-        result
+    // Same as get_all_audits, skipping hidden contracts and falling back to the latest visible audit
+    #[view(getVisibleAudits)]
+    fn get_visible_audits(
+        &self,
+        start_index: usize,
+        limit: usize,
+        kind: OptionalValue<AuditKind>,
+    ) -> MultiValueEncoded<AuditListItem<Self::Api>> {
+        self.latest_audits_page(self.all_contract_hashes(), start_index, limit, kind, true)
     }
 
     #[view(getTotalContracts)]
@@ -124,20 +628,75 @@ pub trait AuditRegistry {
         self.all_contract_hashes().len()
     }
 
+    #[view(getAllAuditsByChain)]
+    fn get_all_audits_by_chain(
+        &self,
+        chain_id: ManagedBuffer,
+        start_index: usize,
+        limit: usize,
+        kind: OptionalValue<AuditKind>,
+    ) -> MultiValueEncoded<AuditListItem<Self::Api>> {
+        self.latest_audits_page(self.chain_contract_hashes(&chain_id), start_index, limit, kind, false)
+    }
+
+    #[view(getVisibleAuditsByChain)]
+    fn get_visible_audits_by_chain(
+        &self,
+        chain_id: ManagedBuffer,
+        start_index: usize,
+        limit: usize,
+        kind: OptionalValue<AuditKind>,
+    ) -> MultiValueEncoded<AuditListItem<Self::Api>> {
+        self.latest_audits_page(self.chain_contract_hashes(&chain_id), start_index, limit, kind, true)
+    }
+
+    #[view(getTotalContractsByChain)]
+    fn get_total_contracts_by_chain(&self, chain_id: ManagedBuffer) -> usize {
+        self.chain_contract_hashes(&chain_id).len()
+    }
+
+    // Computes the registry key of a contract hash within its chain and hashing scheme
+    #[view(getContractKey)]
+    fn get_contract_key(
+        &self,
+        chain_id: ManagedBuffer,
+        hash_algorithm: HashAlgorithm,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+    ) -> ManagedByteArray<Self::Api, 32> {
+        self.compute_contract_key(&ContractNamespace {
+            chain_id,
+            hash_algorithm,
+            contract_hash,
+        })
+    }
+
+    #[view(getContractNamespace)]
+    fn get_contract_namespace(
+        &self,
+        contract_key: ManagedByteArray<Self::Api, 32>,
+    ) -> ContractNamespace<Self::Api> {
+        let namespace_mapper = self.contract_namespace(&contract_key);
+        require!(!namespace_mapper.is_empty(), "No audits found for this contract");
+
+        namespace_mapper.get()
+    }
+
     #[view(getContractAudits)]
     fn get_contract_audits(
         &self,
         contract_hash: ManagedByteArray<Self::Api, 32>,
+        kind: OptionalValue<AuditKind>,
     ) -> MultiValueEncoded<Audit<Self::Api>> {
-        let audits_mapper = self.contract_audits(&contract_hash);
-        let mut result = MultiValueEncoded::new();
-        
-        for i in 0..audits_mapper.len() {
-            let audit = audits_mapper.get(i);
-            result.push(audit);
-        }
-        
-        result
+        self.filtered_contract_audits(&contract_hash, kind, false)
+    }
+
+    #[view(getVisibleContractAudits)]
+    fn get_visible_contract_audits(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        kind: OptionalValue<AuditKind>,
+    ) -> MultiValueEncoded<Audit<Self::Api>> {
+        self.filtered_contract_audits(&contract_hash, kind, true)
     }
 
     #[view(getAuditorHistory)]
@@ -148,66 +707,407 @@ pub trait AuditRegistry {
         let history_mapper = self.auditor_history(&auditor);
         let mut result = MultiValueEncoded::new();
         
-        for i in 0..history_mapper.len() {
-            let hash = history_mapper.get(i);
+        for hash in history_mapper.iter() {
             result.push(hash);
         }
         
         result
     }
 
+    #[view(getVisibleAuditorHistory)]
+    fn get_visible_auditor_history(
+        &self,
+        auditor: ManagedAddress,
+    ) -> MultiValueEncoded<ManagedByteArray<Self::Api, 32>> {
+        let history_mapper = self.auditor_history(&auditor);
+        let mut result = MultiValueEncoded::new();
+
+        for hash in history_mapper.iter() {
+            if !self.hidden_contract(&hash).get() {
+                result.push(hash);
+            }
+        }
+
+        result
+    }
+
     #[view(getLatestAudit)]
     fn get_latest_audit(
         &self,
         contract_hash: ManagedByteArray<Self::Api, 32>,
     ) -> Audit<Self::Api> {
-        let audits_mapper = self.contract_audits(&contract_hash);
-        require!(audits_mapper.len() > 0, "No audits found for this contract");
-        
-        let latest_index = audits_mapper.len() - 1;
-        audits_mapper.get(latest_index)
+        let latest_index = self.audit_count(&contract_hash);
+        require!(latest_index > 0, "No audits found for this contract");
+
+        self.load_audit(&contract_hash, latest_index)
     }
 
-    #[endpoint]
-    fn withdraw(&self) {
-        let caller = self.blockchain().get_caller();
-        let owner = self.owner().get();
-        
-        require!(caller == owner, "Only owner can withdraw");
-        
-        // Get SC balance directly
-        let sc_balance = self.blockchain().get_balance(&self.blockchain().get_sc_address());
-        
-        // Transfer the balance to the owner
-        self.send().direct_egld(&owner, &sc_balance);
+    #[view(getLatestVisibleAudit)]
+    fn get_latest_visible_audit(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+    ) -> Audit<Self::Api> {
+        match self.latest_audit_index(&contract_hash, true) {
+            Some(latest_index) => self.load_audit(&contract_hash, latest_index),
+            None => sc_panic!("No audits found for this contract"),
+        }
+    }
+
+    #[view(isAuditValid)]
+    fn is_audit_valid(&self, contract_hash: ManagedByteArray<Self::Api, 32>, audit_index: usize) -> bool {
+        let header = self.audit_header(&contract_hash, audit_index);
+        let validity_period = self.audit_validity_period().get();
+        !header.revoked
+            && (validity_period == 0
+                || self.blockchain().get_block_timestamp() < header.timestamp + validity_period)
+    }
+
+    // Internal helpers
+    fn own_audit(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize) -> AuditHeader {
+        let header = self.audit_header(contract_hash, audit_index);
+        require!(
+            self.is_auditor_of(&header, &self.blockchain().get_caller()),
+            "Only the auditor can change this audit"
+        );
+        require!(!header.revoked, "Audit is revoked");
+
+        header
+    }
+
+    fn require_auditor_allowed(&self, auditor: &ManagedAddress) {
+        self.require_not_blocked(auditor);
+        if self.admission_required().get() {
+            require!(self.admitted_auditors().contains(auditor), "Auditor not admitted");
+        }
+    }
+
+    // Registration fees stay in the contract balance until withdrawn; returns whether a fee was paid
+    fn collect_registration_fee(&self, audit_count: usize) -> bool {
+        let expected_fee = self.registration_fee().get() * audit_count as u64;
+        require!(
+            *self.call_value().egld() == expected_fee,
+            "Incorrect registration fee"
+        );
+
+        self.distribute_fee_revenue(&expected_fee);
+        expected_fee > 0
+    }
+
+    fn validate_audit(&self, stars: u8, summary: &ManagedBuffer) {
+        require!(stars <= 5, "Stars must be between 0 and 5");
+        require!(!summary.is_empty(), "Summary cannot be empty");
+        require!(summary.len() <= self.get_max_summary_length(), "Summary too long");
+    }
+
+    fn latest_audits_page(
+        &self,
+        hashes: VecMapper<ManagedByteArray<Self::Api, 32>>,
+        start_index: usize,
+        limit: usize,
+        kind: OptionalValue<AuditKind>,
+        visible_only: bool,
+    ) -> MultiValueEncoded<AuditListItem<Self::Api>> {
+        let total_hashes = hashes.len();
+        require!(start_index < total_hashes, "Start index out of bounds");
+
+        let actual_limit = core::cmp::min(limit, total_hashes - start_index);
+        let kind = kind.into_option();
+        let mut result = MultiValueEncoded::new();
+
+        // VecMapper indexes start from 1
+        for i in 1..=actual_limit {
+            let hash = hashes.get(start_index + i);
+            let Some(latest_index) = self.latest_audit_index(&hash, visible_only) else {
+                continue;
+            };
+
+            let latest_header = self.audit_headers(&hash).get(latest_index);
+            if kind.is_none_or(|kind| latest_header.kind == kind) {
+                let summary = self.audit_summary(&hash, latest_index).get();
+                result.push(
+                    (
+                        hash,
+                        latest_header.stars,
+                        summary,
+                        self.auditor_address(latest_header.auditor_id),
+                        latest_header.timestamp,
+                        latest_header.self_attested,
+                    ).into()
+                );
+            }
+        }
+
+        result
+    }
+
+    fn filtered_contract_audits(
+        &self,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+        kind: OptionalValue<AuditKind>,
+        visible_only: bool,
+    ) -> MultiValueEncoded<Audit<Self::Api>> {
+        let kind = kind.into_option();
+        let mut result = MultiValueEncoded::new();
+        if visible_only && self.hidden_contract(contract_hash).get() {
+            return result;
+        }
+
+        for (position, header) in self.audit_headers(contract_hash).iter().enumerate() {
+            let audit_index = position + 1;
+            if visible_only && (header.revoked || self.hidden_audit(contract_hash, audit_index).get()) {
+                continue;
+            }
+            if kind.is_none_or(|kind| header.kind == kind) {
+                result.push(self.load_audit(contract_hash, audit_index));
+            }
+        }
+
+        result
+    }
+
+    // Index of the most recent audit of a contract, optionally ignoring hidden and revoked ones
+    fn latest_audit_index(
+        &self,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+        visible_only: bool,
+    ) -> Option<usize> {
+        let headers_mapper = self.audit_headers(contract_hash);
+        let total_audits = headers_mapper.len();
+        if !visible_only {
+            return (total_audits > 0).then_some(total_audits);
+        }
+        if self.hidden_contract(contract_hash).get() {
+            return None;
+        }
+
+        (1..=total_audits)
+            .rev()
+            .find(|&audit_index| {
+                !self.hidden_audit(contract_hash, audit_index).get()
+                    && !headers_mapper.get(audit_index).revoked
+            })
+    }
+
+    fn compute_contract_key(
+        &self,
+        namespace: &ContractNamespace<Self::Api>,
+    ) -> ManagedByteArray<Self::Api, 32> {
+        // Legacy MultiversX code hashes are stored under the raw hash
+        let is_default_namespace = namespace.chain_id == *DEFAULT_CHAIN_ID
+            && namespace.hash_algorithm == HashAlgorithm::MultiversXCodeHash;
+        if is_default_namespace {
+            return namespace.contract_hash.clone();
+        }
+
+        let mut encoded = ManagedBuffer::new();
+        namespace.top_encode(&mut encoded).unwrap_or_else(|_| sc_panic!("Encoding failed"));
+        self.crypto().keccak256(&encoded)
+    }
+
+    fn validate_provenance(&self, provenance: &AuditProvenance<Self::Api>) {
+        match provenance.kind {
+            AuditKind::Unspecified | AuditKind::Human => {
+                require!(
+                    provenance.model_id.is_empty()
+                        && provenance.model_version.is_empty()
+                        && provenance.prompt_template_hash.is_empty(),
+                    "Model details require an AI or hybrid audit"
+                );
+            },
+            AuditKind::Ai | AuditKind::Hybrid => {
+                require!(!provenance.model_id.is_empty(), "Model id cannot be empty");
+            },
+        }
+
+        require!(
+            provenance.model_id.len() <= MAX_PROVENANCE_FIELD_LENGTH
+                && provenance.model_version.len() <= MAX_PROVENANCE_FIELD_LENGTH
+                && provenance.tool_version.len() <= MAX_PROVENANCE_FIELD_LENGTH,
+            "Provenance field too long"
+        );
+        require!(
+            provenance.prompt_template_hash.is_empty() || provenance.prompt_template_hash.len() == 32,
+            "Prompt template hash must be 32 bytes"
+        );
+    }
+
+    fn validate_chain_id(&self, chain_id: &ManagedBuffer) {
+        require!(!chain_id.is_empty(), "Chain id cannot be empty");
+        require!(chain_id.len() <= MAX_CHAIN_ID_LENGTH, "Chain id too long");
+    }
+
+    fn store_audit(
+        &self,
+        contract_key: &ManagedByteArray<Self::Api, 32>,
+        namespace: &ContractNamespace<Self::Api>,
+        audit: &Audit<Self::Api>,
+        fee_paid: bool,
+    ) -> usize {
+        self.consume_audit_quota(&audit.auditor, contract_key, audit.timestamp);
+        if !self.legacy_contract_audits(contract_key).is_empty() {
+            self.migrate_legacy_contract(contract_key);
+        }
+
+        let audit_index = self.push_audit(contract_key, audit);
+        if fee_paid {
+            self.fee_paid_audit(contract_key, audit_index).set(true);
+            self.add_reward_weight(contract_key, audit_index);
+        }
+        self.index_audit(contract_key, audit_index, audit);
+        self.notify_watchers(contract_key, audit_index, WatchActivity::NewAudit);
+        self.audit_registered_event(
+            contract_key,
+            audit.stars,
+            &audit.auditor,
+            audit.self_attested,
+            EVENT_SCHEMA_VERSION,
+            audit_index,
+            audit.timestamp,
+            &audit.summary,
+        );
+
+        // Add to allContractHashes and the chain index if new
+        if !self.hash_exists(contract_key).get() {
+            self.hash_exists(contract_key).set(true);
+            self.all_contract_hashes().push(contract_key);
+        }
+        self.record_contract_namespace(contract_key, namespace);
+
+        self.record_auditor_history(&audit.auditor, contract_key);
+
+        audit_index
+    }
+
+    // Hashes first registered before chain namespacing exist without a namespace
+    fn record_contract_namespace(
+        &self,
+        contract_key: &ManagedByteArray<Self::Api, 32>,
+        namespace: &ContractNamespace<Self::Api>,
+    ) {
+        if self.contract_namespace(contract_key).is_empty() {
+            self.contract_namespace(contract_key).set(namespace);
+            self.chain_contract_hashes(&namespace.chain_id).push(contract_key);
+            self.known_chains().insert(namespace.chain_id.clone());
+        }
     }
 
-    // Storage mappings
-    #[view(getOwner)]
-    #[storage_mapper("owner")]
-    fn owner(&self) -> SingleValueMapper<ManagedAddress>;
+    // Legacy audits were all MultiversX code hashes stored under the raw hash. They keep their
+    // auditor and timestamp but earn no reward weight, since they never paid into the pool.
+    fn migrate_legacy_contract(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) {
+        let legacy_mapper = self.legacy_contract_audits(contract_hash);
+        let migrated_count = legacy_mapper.len();
+        for legacy_audit in legacy_mapper.iter() {
+            let audit = Audit {
+                stars: legacy_audit.stars,
+                summary: legacy_audit.summary,
+                auditor: legacy_audit.auditor,
+                timestamp: legacy_audit.timestamp,
+                chain_id: ManagedBuffer::from(DEFAULT_CHAIN_ID),
+                hash_algorithm: HashAlgorithm::MultiversXCodeHash,
+                provenance: AuditProvenance::unspecified(),
+                endorsements: 0,
+                contests: 0,
+                project_response: ResponseStatus::NoResponse,
+                self_attested: false,
+                amended_at: 0,
+                revoked: false,
+            };
+            let audit_index = self.push_audit(contract_hash, &audit);
+            self.index_past_audit(contract_hash, audit_index, &audit);
+            self.record_auditor_history(&audit.auditor, contract_hash);
+        }
+        self.legacy_contract_audits(contract_hash).clear();
 
-    #[storage_mapper("contractAudits")]
-    fn contract_audits(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) 
-        -> VecMapper<Audit<Self::Api>>;
+        if !self.hash_exists(contract_hash).get() {
+            self.hash_exists(contract_hash).set(true);
+            self.all_contract_hashes().push(contract_hash);
+        }
+        self.record_contract_namespace(
+            contract_hash,
+            &ContractNamespace {
+                chain_id: ManagedBuffer::from(DEFAULT_CHAIN_ID),
+                hash_algorithm: HashAlgorithm::MultiversXCodeHash,
+                contract_hash: contract_hash.clone(),
+            },
+        );
 
-    #[storage_mapper("auditorHistory")]
-    fn auditor_history(&self, auditor: &ManagedAddress) 
-        -> VecMapper<ManagedByteArray<Self::Api, 32>>;
+        self.legacy_audits_migrated_event(EVENT_SCHEMA_VERSION, contract_hash, migrated_count);
+    }
 
-    #[storage_mapper("allContractHashes")]
-    fn all_contract_hashes(&self) -> VecMapper<ManagedByteArray<Self::Api, 32>>;
+    fn record_auditor_history(&self, auditor: &ManagedAddress, contract_key: &ManagedByteArray<Self::Api, 32>) {
+        // Check if this is the first audit by this auditor for this contract
+        let mut is_new_contract = true;
+        let auditor_history_mapper = self.auditor_history(auditor);
 
-    #[storage_mapper("hashExists")]
-    fn hash_exists(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) -> SingleValueMapper<bool>;
+        for existing_hash in auditor_history_mapper.iter() {
+            if existing_hash == *contract_key {
+                is_new_contract = false;
+                break;
+            }
+        }
+
+        if is_new_contract {
+            self.auditor_history(auditor).push(contract_key);
+        }
+    }
+
+    fn get_team_draft(&self, draft_id: u64) -> TeamAuditDraft<Self::Api> {
+        let draft_mapper = self.team_audit_draft(draft_id);
+        require!(!draft_mapper.is_empty(), "Team audit not found");
+
+        draft_mapper.get()
+    }
+
+    // Flagged as a self-audit if any signer is on the project side
+    fn finalize_team_audit(&self, draft_id: u64, draft: TeamAuditDraft<Self::Api>) {
+        self.require_auditor_allowed(&draft.lead);
+        self.team_audit_draft(draft_id).clear();
+        self.team_audit_signatures(draft_id).clear();
+        self.unlock_funds(&EgldOrEsdtTokenIdentifier::egld(), &draft.fee);
+        self.distribute_fee_revenue(&draft.fee);
+
+        let namespace = ContractNamespace {
+            chain_id: ManagedBuffer::from(DEFAULT_CHAIN_ID),
+            hash_algorithm: HashAlgorithm::MultiversXCodeHash,
+            contract_hash: draft.contract_hash.clone(),
+        };
+        let contract_key = self.compute_contract_key(&namespace);
+        let mut self_attested = self.check_self_audit(&contract_key, &draft.lead);
+        for co_auditor in draft.co_auditors.iter() {
+            self_attested |= self.check_self_audit(&contract_key, &co_auditor);
+        }
+
+        let new_audit = Audit {
+            stars: draft.stars,
+            summary: draft.summary,
+            auditor: draft.lead,
+            timestamp: self.blockchain().get_block_timestamp(),
+            chain_id: namespace.chain_id.clone(),
+            hash_algorithm: HashAlgorithm::MultiversXCodeHash,
+            provenance: AuditProvenance::unspecified(),
+            endorsements: 0,
+            contests: 0,
+            project_response: ResponseStatus::NoResponse,
+            self_attested,
+            amended_at: 0,
+            revoked: false,
+        };
+        let audit_index = self.store_audit(&contract_key, &namespace, &new_audit, draft.fee > 0);
+
+        for co_auditor in draft.co_auditors.iter() {
+            self.record_auditor_history(&co_auditor, &contract_key);
+        }
+        self.audit_co_signers(&contract_key, audit_index).set(&draft.co_auditors);
+
+        self.team_audit_finalized_event(draft_id, &contract_key, audit_index);
+    }
 
     // Events
-    #[event("auditRegistered")]
-    fn audit_registered_event(
+    #[event("auditBatchRegistered")]
+    fn audit_batch_registered_event(
         &self,
-        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
-        #[indexed] stars: u8,
         #[indexed] auditor: &ManagedAddress,
-        summary: &ManagedBuffer,
+        #[indexed] count: usize,
+        timestamp: u64,
     );
 }
\ No newline at end of file
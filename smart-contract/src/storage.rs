@@ -0,0 +1,223 @@
+multiversx_sc::imports!();
+
+use crate::{
+    lineage::CodeVersion, Audit, AuditHeader, AuditProvenance, ContractNamespace, HashAlgorithm, LegacyAudit,
+    DEFAULT_CHAIN_ID, DEFAULT_MAX_SUMMARY_LENGTH,
+};
+
+// Storage shared by the registry and its modules
+#[multiversx_sc::module]
+pub trait StorageModule {
+    #[view(getOwner)]
+    #[storage_mapper("owner")]
+    fn owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // Audits are split into a fixed-size header, the summary and the optional provenance,
+    // so list views and counters never load the variable-length parts
+    #[storage_mapper("auditHeaders")]
+    fn audit_headers(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) -> VecMapper<AuditHeader>;
+
+    #[storage_mapper("auditSummary")]
+    fn audit_summary(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize)
+        -> SingleValueMapper<ManagedBuffer>;
+
+    // Only written for audits with a specified provenance
+    #[storage_mapper("auditProvenance")]
+    fn audit_provenance(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize)
+        -> SingleValueMapper<AuditProvenance<Self::Api>>;
+
+    // Pre-header layout, emptied per contract by migrate_legacy_audits
+    #[storage_mapper("contractAudits")]
+    fn legacy_contract_audits(&self, contract_hash: &ManagedByteArray<Self::Api, 32>)
+        -> VecMapper<LegacyAudit<Self::Api>>;
+
+    // Headers refer to auditors by id instead of repeating the 32-byte address
+    #[storage_mapper("auditorIds")]
+    fn auditor_ids(&self) -> AddressToIdMapper;
+
+    #[storage_mapper("auditorHistory")]
+    fn auditor_history(&self, auditor: &ManagedAddress) 
+        -> VecMapper<ManagedByteArray<Self::Api, 32>>;
+
+    #[storage_mapper("allContractHashes")]
+    fn all_contract_hashes(&self) -> VecMapper<ManagedByteArray<Self::Api, 32>>;
+
+    #[storage_mapper("hashExists")]
+    fn hash_exists(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("contractNamespace")]
+    fn contract_namespace(&self, contract_key: &ManagedByteArray<Self::Api, 32>)
+        -> SingleValueMapper<ContractNamespace<Self::Api>>;
+
+    #[storage_mapper("chainContractHashes")]
+    fn chain_contract_hashes(&self, chain_id: &ManagedBuffer)
+        -> VecMapper<ManagedByteArray<Self::Api, 32>>;
+
+    #[view(getKnownChains)]
+    #[storage_mapper("knownChains")]
+    fn known_chains(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    // Code hashes deployed at a contract address, oldest first
+    #[storage_mapper("contractLineage")]
+    fn contract_lineage(&self, sc_address: &ManagedAddress) -> VecMapper<CodeVersion<Self::Api>>;
+
+    // Set by moderators; the star index and the visible views skip hidden audits
+    #[storage_mapper("hiddenAudit")]
+    fn hidden_audit(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize)
+        -> SingleValueMapper<bool>;
+
+    #[view(isContractHidden)]
+    #[storage_mapper("hiddenContract")]
+    fn hidden_contract(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) -> SingleValueMapper<bool>;
+
+    #[view(getBlockedAuditors)]
+    #[storage_mapper("blockedAuditors")]
+    fn blocked_auditors(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Registry parameters, changed through governance
+    #[storage_mapper("maxSummaryLength")]
+    fn max_summary_length(&self) -> SingleValueMapper<usize>;
+
+    #[view(getRegistrationFee)]
+    #[storage_mapper("registrationFee")]
+    fn registration_fee(&self) -> SingleValueMapper<BigUint>;
+
+    // Zero means audits never expire
+    #[view(getAuditValidityPeriod)]
+    #[storage_mapper("auditValidityPeriod")]
+    fn audit_validity_period(&self) -> SingleValueMapper<u64>;
+
+    #[view(isAdmissionRequired)]
+    #[storage_mapper("admissionRequired")]
+    fn admission_required(&self) -> SingleValueMapper<bool>;
+
+    #[view(getAdmittedAuditors)]
+    #[storage_mapper("admittedAuditors")]
+    fn admitted_auditors(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getMaxSummaryLength)]
+    fn get_max_summary_length(&self) -> usize {
+        let max_summary_length_mapper = self.max_summary_length();
+        if max_summary_length_mapper.is_empty() {
+            DEFAULT_MAX_SUMMARY_LENGTH
+        } else {
+            max_summary_length_mapper.get()
+        }
+    }
+
+    // Funds held on behalf of users, which the owner cannot withdraw
+    #[view(getLockedFunds)]
+    #[storage_mapper("lockedFunds")]
+    fn locked_funds(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    fn lock_funds(&self, token: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        self.locked_funds(token).update(|locked| *locked += amount);
+    }
+
+    fn unlock_funds(&self, token: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        self.locked_funds(token).update(|locked| *locked -= amount);
+    }
+
+    // Audit accessors
+    fn audit_count(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) -> usize {
+        self.audit_headers(contract_hash).len()
+    }
+
+    fn audit_header(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize) -> AuditHeader {
+        let headers_mapper = self.audit_headers(contract_hash);
+        require!(
+            audit_index >= 1 && audit_index <= headers_mapper.len(),
+            "Audit not found"
+        );
+
+        headers_mapper.get(audit_index)
+    }
+
+    fn set_audit_header(
+        &self,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        header: &AuditHeader,
+    ) {
+        self.audit_headers(contract_hash).set(audit_index, header);
+    }
+
+    // Public feeds leave out revoked audits and those hidden by moderators, alone or with their contract
+    fn is_audit_visible(
+        &self,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        header: &AuditHeader,
+    ) -> bool {
+        !header.revoked
+            && !self.hidden_contract(contract_hash).get()
+            && !self.hidden_audit(contract_hash, audit_index).get()
+    }
+
+    fn auditor_address(&self, auditor_id: u64) -> ManagedAddress {
+        self.auditor_ids()
+            .get_address(auditor_id)
+            .unwrap_or_else(|| sc_panic!("Unknown auditor"))
+    }
+
+    fn is_auditor_of(&self, header: &AuditHeader, address: &ManagedAddress) -> bool {
+        self.auditor_ids().get_id(address) == header.auditor_id
+    }
+
+    // Reassembles the full audit from its header, summary, provenance and namespace
+    fn load_audit(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit_index: usize) -> Audit<Self::Api> {
+        let header = self.audit_header(contract_hash, audit_index);
+        // Hashes registered before chain namespacing are MultiversX code hashes
+        let namespace_mapper = self.contract_namespace(contract_hash);
+        let (chain_id, hash_algorithm) = if namespace_mapper.is_empty() {
+            (ManagedBuffer::from(DEFAULT_CHAIN_ID), HashAlgorithm::MultiversXCodeHash)
+        } else {
+            let namespace = namespace_mapper.get();
+            (namespace.chain_id, namespace.hash_algorithm)
+        };
+        let provenance_mapper = self.audit_provenance(contract_hash, audit_index);
+        let provenance = if provenance_mapper.is_empty() {
+            AuditProvenance::unspecified()
+        } else {
+            provenance_mapper.get()
+        };
+
+        Audit {
+            stars: header.stars,
+            summary: self.audit_summary(contract_hash, audit_index).get(),
+            auditor: self.auditor_address(header.auditor_id),
+            timestamp: header.timestamp,
+            chain_id,
+            hash_algorithm,
+            provenance,
+            endorsements: header.endorsements,
+            contests: header.contests,
+            project_response: header.project_response,
+            self_attested: header.self_attested,
+            amended_at: header.amended_at,
+            revoked: header.revoked,
+        }
+    }
+
+    fn push_audit(&self, contract_hash: &ManagedByteArray<Self::Api, 32>, audit: &Audit<Self::Api>) -> usize {
+        let header = AuditHeader {
+            stars: audit.stars,
+            auditor_id: self.auditor_ids().get_id_or_insert(&audit.auditor),
+            timestamp: audit.timestamp,
+            kind: audit.provenance.kind,
+            endorsements: audit.endorsements,
+            contests: audit.contests,
+            project_response: audit.project_response,
+            self_attested: audit.self_attested,
+            amended_at: audit.amended_at,
+            revoked: audit.revoked,
+        };
+        let audit_index = self.audit_headers(contract_hash).push(&header);
+        self.audit_summary(contract_hash, audit_index).set(&audit.summary);
+        if !audit.provenance.is_unspecified() {
+            self.audit_provenance(contract_hash, audit_index).set(&audit.provenance);
+        }
+
+        audit_index
+    }
+}
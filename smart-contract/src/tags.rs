@@ -0,0 +1,241 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{indexes, moderation, project_response, rewards, storage, watchlist};
+
+pub const MAX_TAG_LENGTH: usize = 32;
+pub const MAX_TAGS_PER_CONTRACT: usize = 10;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ContractCategory {
+    DeFi,
+    Nft,
+    Dao,
+    Bridge,
+    Token,
+    Other,
+}
+
+// Categories and free-form tags for browsing audited contracts
+#[multiversx_sc::module]
+pub trait TagsModule:
+    storage::StorageModule
+    + indexes::IndexesModule
+    + rewards::RewardsModule
+    + moderation::ModerationModule
+    + watchlist::WatchlistModule
+    + project_response::ProjectResponseModule
+{
+    #[endpoint]
+    fn set_contract_category(&self, contract_hash: ManagedByteArray<Self::Api, 32>, category: ContractCategory) {
+        self.require_contract_curator(&contract_hash);
+
+        let category_mapper = self.contract_category(&contract_hash);
+        if let Some(previous_category) = category_mapper.get() {
+            self.category_contracts(previous_category)
+                .swap_remove(&contract_hash);
+        }
+        category_mapper.set(Some(category));
+        self.category_contracts(category).insert(contract_hash.clone());
+
+        self.contract_category_set_event(&contract_hash, category);
+    }
+
+    // Tags are lowercase ASCII letters, digits and dashes
+    #[endpoint]
+    fn add_contract_tags(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        tags: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.require_contract_curator(&contract_hash);
+
+        let mut tags_mapper = self.contract_tags(&contract_hash);
+        for tag in tags.into_iter() {
+            self.validate_tag(&tag);
+            if tags_mapper.insert(tag.clone()) {
+                self.tag_contracts(&tag).insert(contract_hash.clone());
+                self.contract_tagged_event(&contract_hash, &tag, true);
+            }
+        }
+        require!(tags_mapper.len() <= MAX_TAGS_PER_CONTRACT, "Too many tags");
+    }
+
+    #[endpoint]
+    fn remove_contract_tags(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        tags: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.require_contract_curator(&contract_hash);
+
+        let mut tags_mapper = self.contract_tags(&contract_hash);
+        for tag in tags.into_iter() {
+            if tags_mapper.swap_remove(&tag) {
+                self.tag_contracts(&tag).swap_remove(&contract_hash);
+                self.contract_tagged_event(&contract_hash, &tag, false);
+            }
+        }
+    }
+
+    // Views
+    #[view(getContractCategory)]
+    fn get_contract_category(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+    ) -> OptionalValue<ContractCategory> {
+        self.contract_category(&contract_hash).get().into()
+    }
+
+    #[view(getContractTags)]
+    fn get_contract_tags(&self, contract_hash: ManagedByteArray<Self::Api, 32>) -> MultiValueEncoded<ManagedBuffer> {
+        let mut result = MultiValueEncoded::new();
+        for tag in self.contract_tags(&contract_hash).iter() {
+            result.push(tag);
+        }
+
+        result
+    }
+
+    #[view(getContractsByTag)]
+    fn get_contracts_by_tag(
+        &self,
+        tag: ManagedBuffer,
+        start_index: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<ManagedByteArray<Self::Api, 32>> {
+        self.contract_hashes_page(self.tag_contracts(&tag), start_index, limit, false)
+    }
+
+    // Same as getContractsByTag, skipping hidden contracts within the page
+    #[view(getVisibleContractsByTag)]
+    fn get_visible_contracts_by_tag(
+        &self,
+        tag: ManagedBuffer,
+        start_index: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<ManagedByteArray<Self::Api, 32>> {
+        self.contract_hashes_page(self.tag_contracts(&tag), start_index, limit, true)
+    }
+
+    #[view(getTotalContractsByTag)]
+    fn get_total_contracts_by_tag(&self, tag: ManagedBuffer) -> usize {
+        self.tag_contracts(&tag).len()
+    }
+
+    #[view(getContractsByCategory)]
+    fn get_contracts_by_category(
+        &self,
+        category: ContractCategory,
+        start_index: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<ManagedByteArray<Self::Api, 32>> {
+        self.contract_hashes_page(self.category_contracts(category), start_index, limit, false)
+    }
+
+    #[view(getVisibleContractsByCategory)]
+    fn get_visible_contracts_by_category(
+        &self,
+        category: ContractCategory,
+        start_index: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<ManagedByteArray<Self::Api, 32>> {
+        self.contract_hashes_page(self.category_contracts(category), start_index, limit, true)
+    }
+
+    #[view(getTotalContractsByCategory)]
+    fn get_total_contracts_by_category(&self, category: ContractCategory) -> usize {
+        self.category_contracts(category).len()
+    }
+
+    // Internal helpers
+    // Moderators, the linked project owner and the contract's auditors can curate it
+    fn require_contract_curator(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) {
+        require!(self.hash_exists(contract_hash).get(), "No audits found for this contract");
+
+        let caller = self.blockchain().get_caller();
+        if caller == self.owner().get() || self.moderators().contains(&caller) {
+            return;
+        }
+
+        let link_mapper = self.project_link(contract_hash);
+        if !link_mapper.is_empty() && link_mapper.get().project_owner == caller {
+            return;
+        }
+
+        require!(
+            self.auditor_history(&caller).iter().any(|hash| hash == *contract_hash),
+            "Not allowed to curate this contract"
+        );
+    }
+
+    fn validate_tag(&self, tag: &ManagedBuffer) {
+        require!(!tag.is_empty() && tag.len() <= MAX_TAG_LENGTH, "Invalid tag length");
+
+        let mut tag_bytes = [0u8; MAX_TAG_LENGTH];
+        let tag_bytes = tag.load_to_byte_array(&mut tag_bytes);
+        require!(
+            tag_bytes
+                .iter()
+                .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || *byte == b'-'),
+            "Invalid tag characters"
+        );
+    }
+
+    // Set mapper indexes start from 1; hidden contracts still count towards the page
+    fn contract_hashes_page(
+        &self,
+        hashes: UnorderedSetMapper<ManagedByteArray<Self::Api, 32>>,
+        start_index: usize,
+        limit: usize,
+        visible_only: bool,
+    ) -> MultiValueEncoded<ManagedByteArray<Self::Api, 32>> {
+        let total_hashes = hashes.len();
+        require!(start_index < total_hashes, "Start index out of bounds");
+
+        let actual_limit = core::cmp::min(limit, total_hashes - start_index);
+        let mut result = MultiValueEncoded::new();
+        for i in 1..=actual_limit {
+            let contract_hash = hashes.get_by_index(start_index + i);
+            if visible_only && self.hidden_contract(&contract_hash).get() {
+                continue;
+            }
+            result.push(contract_hash);
+        }
+
+        result
+    }
+
+    // Storage mappings
+    // Wrapped in an Option because the first variant encodes to empty storage
+    #[storage_mapper("contractCategory")]
+    fn contract_category(&self, contract_hash: &ManagedByteArray<Self::Api, 32>)
+        -> SingleValueMapper<Option<ContractCategory>>;
+
+    #[storage_mapper("categoryContracts")]
+    fn category_contracts(&self, category: ContractCategory)
+        -> UnorderedSetMapper<ManagedByteArray<Self::Api, 32>>;
+
+    #[storage_mapper("contractTags")]
+    fn contract_tags(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("tagContracts")]
+    fn tag_contracts(&self, tag: &ManagedBuffer) -> UnorderedSetMapper<ManagedByteArray<Self::Api, 32>>;
+
+    // Events
+    #[event("contractCategorySet")]
+    fn contract_category_set_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] category: ContractCategory,
+    );
+
+    #[event("contractTagged")]
+    fn contract_tagged_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] tag: &ManagedBuffer,
+        #[indexed] added: bool,
+    );
+}
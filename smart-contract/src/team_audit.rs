@@ -0,0 +1,104 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::storage;
+
+pub const MAX_CO_AUDITORS: usize = 10;
+
+// A team audit waiting for its co-auditors; registered under the lead once everyone has signed
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct TeamAuditDraft<M: ManagedTypeApi> {
+    pub lead: ManagedAddress<M>,
+    pub contract_hash: ManagedByteArray<M, 32>,
+    pub stars: u8,
+    pub summary: ManagedBuffer<M>,
+    pub co_auditors: ManagedVec<M, ManagedAddress<M>>,
+    // Registration fee held until the audit is final or cancelled
+    pub fee: BigUint<M>,
+    pub proposed_at: u64,
+}
+
+// Storage, views and events for audits signed by several auditors
+#[multiversx_sc::module]
+pub trait TeamAuditModule: storage::StorageModule {
+    // Views
+    #[view(getTeamAuditDraft)]
+    fn get_team_audit_draft(&self, draft_id: u64) -> OptionalValue<TeamAuditDraft<Self::Api>> {
+        let draft_mapper = self.team_audit_draft(draft_id);
+        if draft_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(draft_mapper.get())
+        }
+    }
+
+    #[view(getTeamAuditSignatures)]
+    fn get_team_audit_signatures(&self, draft_id: u64) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        for signer in self.team_audit_signatures(draft_id).iter() {
+            result.push(signer);
+        }
+
+        result
+    }
+
+    // The lead auditor first, followed by the co-auditors who signed
+    #[view(getAuditSigners)]
+    fn get_audit_signers(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        let header = self.audit_header(&contract_hash, audit_index);
+
+        let mut result = MultiValueEncoded::new();
+        result.push(self.auditor_address(header.auditor_id));
+        for co_signer in self.audit_co_signers(&contract_hash, audit_index).get().iter() {
+            result.push(co_signer.clone_value());
+        }
+
+        result
+    }
+
+    // Storage mappings
+    #[storage_mapper("lastTeamAuditId")]
+    fn last_team_audit_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("teamAuditDraft")]
+    fn team_audit_draft(&self, draft_id: u64) -> SingleValueMapper<TeamAuditDraft<Self::Api>>;
+
+    #[storage_mapper("teamAuditSignatures")]
+    fn team_audit_signatures(&self, draft_id: u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("auditCoSigners")]
+    fn audit_co_signers(
+        &self,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+    ) -> SingleValueMapper<ManagedVec<Self::Api, ManagedAddress<Self::Api>>>;
+
+    // Events
+    #[event("teamAuditProposed")]
+    fn team_audit_proposed_event(
+        &self,
+        #[indexed] draft_id: u64,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] lead: &ManagedAddress,
+        co_auditors: &ManagedVec<Self::Api, ManagedAddress<Self::Api>>,
+    );
+
+    #[event("teamAuditSigned")]
+    fn team_audit_signed_event(&self, #[indexed] draft_id: u64, #[indexed] co_auditor: &ManagedAddress);
+
+    #[event("teamAuditFinalized")]
+    fn team_audit_finalized_event(
+        &self,
+        #[indexed] draft_id: u64,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] audit_index: usize,
+    );
+
+    #[event("teamAuditCancelled")]
+    fn team_audit_cancelled_event(&self, #[indexed] draft_id: u64, #[indexed] lead: &ManagedAddress);
+}
@@ -0,0 +1,293 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    community_vote,
+    coverage::{self, CoverageTerms},
+    events::{self, EVENT_SCHEMA_VERSION},
+    indexes, moderation, project_response,
+    rate_limit::{self, RateLimits},
+    rewards, storage, watchlist,
+};
+
+// Queued actions wait two days by default and expire two weeks after their ETA
+pub const DEFAULT_TIMELOCK_DELAY: u64 = 172_800;
+pub const MIN_TIMELOCK_DELAY: u64 = 3_600;
+pub const MAX_TIMELOCK_DELAY: u64 = 2_592_000;
+pub const TIMELOCK_GRACE_PERIOD: u64 = 1_209_600;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub enum AdminAction<M: ManagedTypeApi> {
+    Withdraw,
+    SetRegistrationFee(BigUint<M>),
+    RemoveAuditor(ManagedAddress<M>),
+    // Code hash the owner commits to deploying; see consume_upgrade_approval
+    ApproveUpgrade(ManagedByteArray<M, 32>),
+    SetTimelockDelay(u64),
+    SetAuditorShare(u32),
+    ChangeOwner(ManagedAddress<M>),
+    AdmitAuditor(ManagedAddress<M>),
+    SetVotingPeriod(u64),
+    SetRateLimits(RateLimits),
+    SetRejectSelfAudits(bool),
+    SetCoverageTerms(CoverageTerms),
+    AddArbiter(ManagedAddress<M>),
+    RemoveArbiter(ManagedAddress<M>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AdminActionStatus {
+    Queued,
+    Cancelled,
+    Executed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct QueuedAction<M: ManagedTypeApi> {
+    pub action: AdminAction<M>,
+    pub queued_at: u64,
+    pub eta: u64,
+    pub status: AdminActionStatus,
+}
+
+// Sensitive owner actions only take effect after a public delay
+#[multiversx_sc::module]
+pub trait TimelockModule:
+    storage::StorageModule
+    + rewards::RewardsModule
+    + events::EventsModule
+    + community_vote::CommunityVoteModule
+    + rate_limit::RateLimitModule
+    + indexes::IndexesModule
+    + moderation::ModerationModule
+    + watchlist::WatchlistModule
+    + project_response::ProjectResponseModule
+    + coverage::CoverageModule
+{
+    #[endpoint]
+    fn queue_admin_action(&self, action: AdminAction<Self::Api>) -> usize {
+        self.require_timelock_owner();
+        match &action {
+            AdminAction::SetTimelockDelay(delay) => require!(
+                *delay >= MIN_TIMELOCK_DELAY && *delay <= MAX_TIMELOCK_DELAY,
+                "Timelock delay out of range"
+            ),
+            AdminAction::SetAuditorShare(share_bps) => require!(
+                *share_bps <= rewards::MAX_AUDITOR_SHARE_BPS,
+                "Auditor share out of range"
+            ),
+            AdminAction::SetVotingPeriod(voting_period) => require!(
+                *voting_period > 0 && *voting_period <= community_vote::MAX_VOTING_PERIOD,
+                "Voting period out of range"
+            ),
+            AdminAction::SetRateLimits(limits) => self.require_valid_rate_limits(limits),
+            AdminAction::SetCoverageTerms(terms) => self.require_valid_coverage_terms(terms),
+            _ => {},
+        }
+
+        let queued_at = self.blockchain().get_block_timestamp();
+        let queued_action = QueuedAction {
+            action,
+            queued_at,
+            eta: queued_at + self.get_timelock_delay(),
+            status: AdminActionStatus::Queued,
+        };
+        let action_id = self.admin_actions().push(&queued_action);
+
+        self.admin_action_queued_event(action_id, queued_action.eta, &queued_action.action);
+
+        action_id
+    }
+
+    #[endpoint]
+    fn cancel_admin_action(&self, action_id: usize) {
+        self.require_timelock_owner();
+
+        let mut queued_action = self.get_admin_action(action_id);
+        require!(
+            queued_action.status == AdminActionStatus::Queued,
+            "Action is not queued"
+        );
+
+        queued_action.status = AdminActionStatus::Cancelled;
+        self.admin_actions().set(action_id, &queued_action);
+
+        self.admin_action_cancelled_event(action_id);
+    }
+
+    #[endpoint]
+    fn execute_admin_action(&self, action_id: usize) {
+        self.require_timelock_owner();
+
+        let mut queued_action = self.get_admin_action(action_id);
+        require!(
+            queued_action.status == AdminActionStatus::Queued,
+            "Action is not queued"
+        );
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(current_timestamp >= queued_action.eta, "Timelock not elapsed");
+        require!(
+            current_timestamp < queued_action.eta + TIMELOCK_GRACE_PERIOD,
+            "Action expired"
+        );
+
+        queued_action.status = AdminActionStatus::Executed;
+        self.admin_actions().set(action_id, &queued_action);
+
+        match &queued_action.action {
+            AdminAction::Withdraw => {
+                // Leave out funds locked for users and accrued auditor rewards
+                let sc_balance = self.blockchain().get_balance(&self.blockchain().get_sc_address());
+                let locked = self.locked_funds(&EgldOrEsdtTokenIdentifier::egld()).get();
+                let owner = self.owner().get();
+                let amount = sc_balance - locked;
+                self.send().direct_egld(&owner, &amount);
+                self.funds_withdrawn_event(EVENT_SCHEMA_VERSION, &owner, &amount);
+            },
+            AdminAction::SetRegistrationFee(fee) => {
+                self.change_registration_fee(fee);
+            },
+            AdminAction::RemoveAuditor(auditor) => {
+                self.remove_auditor(auditor);
+            },
+            AdminAction::ApproveUpgrade(code_hash) => {
+                self.approved_upgrade().set(code_hash);
+            },
+            AdminAction::SetTimelockDelay(delay) => {
+                self.timelock_delay().set(delay);
+            },
+            AdminAction::SetAuditorShare(share_bps) => {
+                self.auditor_share_bps().set(share_bps);
+            },
+            AdminAction::AdmitAuditor(auditor) => {
+                self.admit_auditor(auditor);
+            },
+            AdminAction::SetVotingPeriod(voting_period) => {
+                self.voting_period().set(voting_period);
+            },
+            AdminAction::SetRateLimits(limits) => {
+                self.change_rate_limits(limits);
+            },
+            AdminAction::SetRejectSelfAudits(reject) => {
+                self.reject_self_audits().set(reject);
+            },
+            AdminAction::SetCoverageTerms(terms) => {
+                self.change_coverage_terms(terms);
+            },
+            AdminAction::AddArbiter(arbiter) => {
+                self.arbiters().insert(arbiter.clone());
+            },
+            AdminAction::RemoveArbiter(arbiter) => {
+                self.arbiters().swap_remove(arbiter);
+            },
+            AdminAction::ChangeOwner(new_owner) => {
+                let previous_owner = self.owner().replace(new_owner);
+                self.owner_changed_event(EVENT_SCHEMA_VERSION, &previous_owner, new_owner, current_timestamp);
+            },
+        }
+
+        self.admin_action_executed_event(action_id, &queued_action.action);
+    }
+
+    // Views
+    #[view(getAdminAction)]
+    fn get_admin_action(&self, action_id: usize) -> QueuedAction<Self::Api> {
+        let actions_mapper = self.admin_actions();
+        require!(
+            action_id >= 1 && action_id <= actions_mapper.len(),
+            "Action not found"
+        );
+
+        actions_mapper.get(action_id)
+    }
+
+    // Pending actions that have not expired, with their ids
+    #[view(getQueuedActions)]
+    fn get_queued_actions(&self) -> MultiValueEncoded<MultiValue2<usize, QueuedAction<Self::Api>>> {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut result = MultiValueEncoded::new();
+        for (action_id, queued_action) in self.admin_actions().iter().enumerate() {
+            if queued_action.status == AdminActionStatus::Queued
+                && current_timestamp < queued_action.eta + TIMELOCK_GRACE_PERIOD
+            {
+                result.push((action_id + 1, queued_action).into());
+            }
+        }
+
+        result
+    }
+
+    #[view(getTimelockDelay)]
+    fn get_timelock_delay(&self) -> u64 {
+        let delay_mapper = self.timelock_delay();
+        if delay_mapper.is_empty() {
+            DEFAULT_TIMELOCK_DELAY
+        } else {
+            delay_mapper.get()
+        }
+    }
+
+    // Internal helpers
+    fn require_timelock_owner(&self) {
+        require!(
+            self.blockchain().get_caller() == self.owner().get(),
+            "Only owner can manage admin actions"
+        );
+    }
+
+    // Upgrades need an executed approval, except the first one onto timelocked code.
+    // The VM does not expose the incoming code hash to the contract, so the approval
+    // cannot be matched against the deployed code here: the owner, expected to be a
+    // multisig, has to check the upgrade's code hash against getApprovedUpgrade before
+    // signing it. The approval still gives users the timelock delay to react.
+    fn consume_upgrade_approval(&self) {
+        if !self.timelock_enabled().get() {
+            self.timelock_enabled().set(true);
+            return;
+        }
+
+        require!(!self.approved_upgrade().is_empty(), "Upgrade not approved");
+        let code_hash = self.approved_upgrade().take();
+
+        self.upgrade_applied_event(&code_hash);
+    }
+
+    // Storage mappings
+    #[storage_mapper("adminActions")]
+    fn admin_actions(&self) -> VecMapper<QueuedAction<Self::Api>>;
+
+    #[storage_mapper("timelockDelay")]
+    fn timelock_delay(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("timelockEnabled")]
+    fn timelock_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getApprovedUpgrade)]
+    #[storage_mapper("approvedUpgrade")]
+    fn approved_upgrade(&self) -> SingleValueMapper<ManagedByteArray<Self::Api, 32>>;
+
+    // Events
+    #[event("adminActionQueued")]
+    fn admin_action_queued_event(
+        &self,
+        #[indexed] action_id: usize,
+        #[indexed] eta: u64,
+        action: &AdminAction<Self::Api>,
+    );
+
+    #[event("adminActionCancelled")]
+    fn admin_action_cancelled_event(&self, #[indexed] action_id: usize);
+
+    #[event("adminActionExecuted")]
+    fn admin_action_executed_event(
+        &self,
+        #[indexed] action_id: usize,
+        action: &AdminAction<Self::Api>,
+    );
+
+    #[event("upgradeApplied")]
+    fn upgrade_applied_event(&self, #[indexed] code_hash: &ManagedByteArray<Self::Api, 32>);
+}
@@ -0,0 +1,121 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::storage;
+
+// Keeps the per-address list small enough to return in a single view
+pub const MAX_WATCHED_CONTRACTS: usize = 50;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WatchActivity {
+    NewAudit,
+    Amendment,
+    Dispute,
+    Revocation,
+}
+
+// Addresses following contract hashes; notifiers fan out the watched activity events off-chain
+#[multiversx_sc::module]
+pub trait WatchlistModule: storage::StorageModule {
+    // Hashes can be watched before their first audit
+    #[endpoint]
+    fn watch(&self, contract_hash: ManagedByteArray<Self::Api, 32>) {
+        let caller = self.blockchain().get_caller();
+        let mut watched_mapper = self.watched_contracts(&caller);
+        require!(watched_mapper.insert(contract_hash.clone()), "Already watching this contract");
+        require!(watched_mapper.len() <= MAX_WATCHED_CONTRACTS, "Too many watched contracts");
+
+        self.contract_watchers(&contract_hash).insert(caller.clone());
+        self.contract_watched_event(&contract_hash, &caller, true);
+    }
+
+    #[endpoint]
+    fn unwatch(&self, contract_hash: ManagedByteArray<Self::Api, 32>) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.watched_contracts(&caller).swap_remove(&contract_hash),
+            "Not watching this contract"
+        );
+
+        self.contract_watchers(&contract_hash).swap_remove(&caller);
+        self.contract_watched_event(&contract_hash, &caller, false);
+    }
+
+    // Views
+    #[view(getWatchers)]
+    fn get_watchers(
+        &self,
+        contract_hash: ManagedByteArray<Self::Api, 32>,
+        start_index: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        let watchers = self.contract_watchers(&contract_hash);
+        let total_watchers = watchers.len();
+        require!(start_index < total_watchers, "Start index out of bounds");
+
+        let actual_limit = core::cmp::min(limit, total_watchers - start_index);
+        let mut result = MultiValueEncoded::new();
+
+        // Set mapper indexes start from 1
+        for i in 1..=actual_limit {
+            result.push(watchers.get_by_index(start_index + i));
+        }
+
+        result
+    }
+
+    #[view(getTotalWatchers)]
+    fn get_total_watchers(&self, contract_hash: ManagedByteArray<Self::Api, 32>) -> usize {
+        self.contract_watchers(&contract_hash).len()
+    }
+
+    #[view(getWatchedContracts)]
+    fn get_watched_contracts(&self, watcher: ManagedAddress) -> MultiValueEncoded<ManagedByteArray<Self::Api, 32>> {
+        let mut result = MultiValueEncoded::new();
+        for contract_hash in self.watched_contracts(&watcher).iter() {
+            result.push(contract_hash);
+        }
+
+        result
+    }
+
+    // Internal helpers
+    // Only emitted for hashes someone is watching, so notifiers can subscribe to this identifier alone
+    fn notify_watchers(
+        &self,
+        contract_hash: &ManagedByteArray<Self::Api, 32>,
+        audit_index: usize,
+        activity: WatchActivity,
+    ) {
+        let watcher_count = self.contract_watchers(contract_hash).len();
+        if watcher_count > 0 {
+            self.watched_contract_activity_event(contract_hash, audit_index, activity, watcher_count);
+        }
+    }
+
+    // Storage mappings
+    #[storage_mapper("contractWatchers")]
+    fn contract_watchers(&self, contract_hash: &ManagedByteArray<Self::Api, 32>) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("watchedContracts")]
+    fn watched_contracts(&self, watcher: &ManagedAddress) -> UnorderedSetMapper<ManagedByteArray<Self::Api, 32>>;
+
+    // Events
+    #[event("contractWatched")]
+    fn contract_watched_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] watcher: &ManagedAddress,
+        #[indexed] watching: bool,
+    );
+
+    #[event("watchedContractActivity")]
+    fn watched_contract_activity_event(
+        &self,
+        #[indexed] contract_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] audit_index: usize,
+        #[indexed] activity: WatchActivity,
+        watcher_count: usize,
+    );
+}
@@ -0,0 +1,221 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+fn amend_expect_error(state: &mut RegistryTestState, from: TestAddress, stars: u8, summary: &str, message: &str) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .amend_audit(contract_hash(1), 1usize, stars, ManagedBuffer::from(summary))
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn revoke(state: &mut RegistryTestState, from: TestAddress) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .revoke_audit(contract_hash(1), 1usize)
+        .run();
+}
+
+#[test]
+fn amend_updates_the_audit() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 2, "draft");
+    state.advance_time(100);
+
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .amend_audit(contract_hash(1), 1usize, 4u8, ManagedBuffer::from("final"))
+        .run();
+
+    let audit = state.latest_audit(1);
+    assert_eq!(audit.stars, 4);
+    assert_eq!(audit.summary, ManagedBuffer::from("final"));
+    assert_eq!(audit.timestamp, START_TIMESTAMP);
+    assert_eq!(audit.amended_at, state.timestamp);
+}
+
+#[test]
+fn amend_is_validated() {
+    let mut state = RegistryTestState::new();
+    amend_expect_error(&mut state, AUDITOR, 4, "final", "Audit not found");
+
+    state.register_audit(AUDITOR, 1, 2, "draft");
+    amend_expect_error(
+        &mut state,
+        OTHER_AUDITOR,
+        4,
+        "final",
+        "Only the auditor can change this audit",
+    );
+    amend_expect_error(&mut state, AUDITOR, 6, "final", "Stars must be between 0 and 5");
+    amend_expect_error(&mut state, AUDITOR, 4, "", "Summary cannot be empty");
+
+    revoke(&mut state, AUDITOR);
+    amend_expect_error(&mut state, AUDITOR, 4, "final", "Audit is revoked");
+}
+
+fn amend_audit_of_expect_error(state: &mut RegistryTestState, hash_byte: u8) {
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .amend_audit(contract_hash(hash_byte), 1usize, 1u8, ManagedBuffer::from("replaced"))
+        .returns(ExpectError(4, "Audit already has reviews, votes or a response"))
+        .run();
+}
+
+#[test]
+fn reviewed_voted_or_answered_audits_cannot_be_amended() {
+    let mut state = RegistryTestState::new();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .set_governance_token(GOVERNANCE_TOKEN)
+        .run();
+    state.run_admin_action(proxy::AdminAction::SetVotingPeriod(604_800));
+    state.register_audit(AUDITOR, 1, 4, "reviewed");
+    state.register_audit(AUDITOR, 3, 4, "voted");
+    state.register_audit(OTHER_AUDITOR, 4, 4, "reviewer history");
+
+    state
+        .world
+        .tx()
+        .from(OTHER_AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .review_audit(
+            contract_hash(1),
+            1usize,
+            proxy::ReviewVerdict::Endorse,
+            ManagedBuffer::from("ok"),
+        )
+        .run();
+    amend_audit_of_expect_error(&mut state, 1);
+
+    state.link_project(2);
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .respond_to_audit(
+            contract_hash(2),
+            1usize,
+            proxy::ResponseStatus::Acknowledged,
+            ManagedBuffer::from("noted"),
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .run();
+    amend_audit_of_expect_error(&mut state, 2);
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .vote_on_audit(contract_hash(3), 1usize, true)
+        .payment(TestEsdtTransfer(GOVERNANCE_TOKEN, 0, 10u64))
+        .run();
+    amend_audit_of_expect_error(&mut state, 3);
+
+    // The auditor's own audit without any feedback can still be amended
+    state
+        .world
+        .tx()
+        .from(OTHER_AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .amend_audit(contract_hash(4), 1usize, 3u8, ManagedBuffer::from("amended"))
+        .run();
+}
+
+#[test]
+fn revoke_marks_the_audit_and_notifies_watchers() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "audit");
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .watch(contract_hash(1))
+        .run();
+
+    let logs = state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .revoke_audit(contract_hash(1), 1usize)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(state.latest_audit(1).revoked);
+
+    let watch_log = logs
+        .iter()
+        .find(|log| log.topics[0] == b"watchedContractActivity".to_vec())
+        .unwrap();
+    // Revocation is the fourth watch activity
+    assert_eq!(watch_log.topics[3], vec![3]);
+    assert!(logs.iter().any(|log| log.topics[0] == b"auditRevoked".to_vec()));
+
+    let valid = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .is_audit_valid(contract_hash(1), 1usize)
+        .returns(ReturnsResult)
+        .run();
+    assert!(!valid);
+}
+
+#[test]
+fn revoke_is_validated() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "audit");
+
+    state
+        .world
+        .tx()
+        .from(OTHER_AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .revoke_audit(contract_hash(1), 1usize)
+        .returns(ExpectError(4, "Only the auditor can change this audit"))
+        .run();
+
+    revoke(&mut state, AUDITOR);
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .revoke_audit(contract_hash(1), 1usize)
+        .returns(ExpectError(4, "Audit is revoked"))
+        .run();
+}
@@ -0,0 +1,468 @@
+// Registration, listing, withdrawal, event and auditor-history paths of the core registry;
+// the other endpoints are covered by their module's own *_blackbox_test.rs
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const PROPOSAL_VOTING_PERIOD: u64 = 259_200;
+const PROPOSAL_TIMELOCK: u64 = 172_800;
+const DEFAULT_MAX_SUMMARY_LENGTH: usize = 500;
+const REGISTRATION_FEE: u64 = 5;
+
+fn no_kind() -> OptionalValue<proxy::AuditKind> {
+    OptionalValue::None
+}
+
+fn register_audit_expect_error(
+    state: &mut RegistryTestState,
+    auditor: TestAddress,
+    hash_byte: u8,
+    stars: u8,
+    summary: &[u8],
+    payment: u64,
+    message: &str,
+) {
+    state
+        .world
+        .tx()
+        .from(auditor)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audit(contract_hash(hash_byte), stars, ManagedBuffer::new_from_bytes(summary))
+        .egld(payment)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn all_audits(state: &mut RegistryTestState, start_index: usize, limit: usize) -> Vec<ManagedByteArray<StaticApi, 32>> {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_all_audits(start_index, limit, no_kind())
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|item| item.into_tuple().0)
+        .collect()
+}
+
+fn auditor_history(state: &mut RegistryTestState, auditor: TestAddress) -> Vec<ManagedByteArray<StaticApi, 32>> {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_auditor_history(auditor.to_managed_address())
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn register_audit_stores_the_audit() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "no issues found");
+
+    let audit = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_latest_audit(contract_hash(1))
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(audit.stars, 4);
+    assert_eq!(audit.summary, ManagedBuffer::from("no issues found"));
+    assert_eq!(audit.auditor, AUDITOR.to_managed_address());
+    assert_eq!(audit.timestamp, START_TIMESTAMP);
+    assert!(!audit.self_attested);
+    assert!(!audit.revoked);
+}
+
+#[test]
+fn register_audit_rejects_invalid_stars() {
+    let mut state = RegistryTestState::new();
+    register_audit_expect_error(
+        &mut state,
+        AUDITOR,
+        1,
+        6,
+        b"too good",
+        0,
+        "Stars must be between 0 and 5",
+    );
+}
+
+#[test]
+fn register_audit_rejects_empty_summary() {
+    let mut state = RegistryTestState::new();
+    register_audit_expect_error(&mut state, AUDITOR, 1, 3, b"", 0, "Summary cannot be empty");
+}
+
+#[test]
+fn register_audit_rejects_long_summary() {
+    let mut state = RegistryTestState::new();
+    let summary = [b'a'; DEFAULT_MAX_SUMMARY_LENGTH + 1];
+    register_audit_expect_error(&mut state, AUDITOR, 1, 3, &summary, 0, "Summary too long");
+
+    // Exactly at the limit is accepted
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audit(
+            contract_hash(1),
+            3u8,
+            ManagedBuffer::new_from_bytes(&summary[..DEFAULT_MAX_SUMMARY_LENGTH]),
+        )
+        .run();
+}
+
+#[test]
+fn register_audit_rejects_incorrect_fee() {
+    let mut state = RegistryTestState::new();
+    register_audit_expect_error(&mut state, AUDITOR, 1, 3, b"paid", 1, "Incorrect registration fee");
+
+    state.run_admin_action(proxy::AdminAction::SetRegistrationFee(BigUint::from(REGISTRATION_FEE)));
+    register_audit_expect_error(&mut state, AUDITOR, 1, 3, b"paid", 0, "Incorrect registration fee");
+    register_audit_expect_error(
+        &mut state,
+        AUDITOR,
+        1,
+        3,
+        b"paid",
+        REGISTRATION_FEE + 1,
+        "Incorrect registration fee",
+    );
+
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audit(contract_hash(1), 3u8, ManagedBuffer::from("paid"))
+        .egld(REGISTRATION_FEE)
+        .run();
+}
+
+#[test]
+fn register_audit_rejects_blocked_auditor() {
+    let mut state = RegistryTestState::new();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .block_auditor(AUDITOR.to_managed_address(), proxy::ModerationReason::Spam)
+        .run();
+
+    register_audit_expect_error(&mut state, AUDITOR, 1, 3, b"spam", 0, "Auditor is blocked");
+}
+
+#[test]
+fn register_audit_rejects_unadmitted_auditor() {
+    let mut state = RegistryTestState::new();
+    for auditor in [AUDITOR, OTHER_AUDITOR, THIRD_AUDITOR] {
+        state.run_admin_action(proxy::AdminAction::AdmitAuditor(auditor.to_managed_address()));
+    }
+
+    let proposal_id = state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .propose(
+            proxy::ProposalAction::SetAdmissionRequired(true),
+            ManagedBuffer::from("admission"),
+        )
+        .returns(ReturnsResult)
+        .run();
+    for auditor in [AUDITOR, OTHER_AUDITOR, THIRD_AUDITOR] {
+        state
+            .world
+            .tx()
+            .from(auditor)
+            .to(REGISTRY)
+            .typed(proxy::AuditRegistryProxy)
+            .vote_proposal(proposal_id, true)
+            .run();
+    }
+    state.advance_time(PROPOSAL_VOTING_PERIOD + PROPOSAL_TIMELOCK);
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .execute_proposal(proposal_id)
+        .run();
+
+    register_audit_expect_error(&mut state, PROJECT_OWNER, 4, 3, b"newcomer", 0, "Auditor not admitted");
+
+    state.run_admin_action(proxy::AdminAction::AdmitAuditor(PROJECT_OWNER.to_managed_address()));
+    state.register_audit(PROJECT_OWNER, 4, 3, "newcomer");
+}
+
+#[test]
+fn register_audit_rejects_self_audits_when_configured() {
+    let mut state = RegistryTestState::new();
+    state.world.account(PROJECT_SC).nonce(1).code(CODE_PATH);
+    // Only audited contracts can be linked to a project
+    state.register_audit(AUDITOR, 1, 4, "independent audit");
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .link_project(
+            contract_hash(1),
+            PROJECT_SC.to_managed_address(),
+            PROJECT_OWNER.to_managed_address(),
+        )
+        .run();
+
+    // Flagged by default
+    state.register_audit(PROJECT_OWNER, 1, 5, "our own code");
+    let audit = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_latest_audit(contract_hash(1))
+        .returns(ReturnsResult)
+        .run();
+    assert!(audit.self_attested);
+
+    state.run_admin_action(proxy::AdminAction::SetRejectSelfAudits(true));
+    register_audit_expect_error(
+        &mut state,
+        PROJECT_OWNER,
+        1,
+        5,
+        b"our own code",
+        0,
+        "Self-audits are not allowed",
+    );
+}
+
+#[test]
+fn register_audit_enforces_rate_limits() {
+    let mut state = RegistryTestState::new();
+    state.run_admin_action(proxy::AdminAction::SetRateLimits(proxy::RateLimits {
+        max_audits_per_window: 2,
+        window_duration: 3_600,
+        same_contract_cooldown: 600,
+    }));
+
+    state.register_audit(AUDITOR, 1, 3, "first");
+    register_audit_expect_error(&mut state, AUDITOR, 1, 3, b"again", 0, "Contract audited too recently");
+
+    state.register_audit(AUDITOR, 2, 3, "second");
+    register_audit_expect_error(&mut state, AUDITOR, 3, 3, b"third", 0, "Audit quota exceeded");
+
+    state.advance_time(3_600);
+    state.register_audit(AUDITOR, 3, 3, "third");
+}
+
+#[test]
+fn get_all_audits_bounds() {
+    let mut state = RegistryTestState::new();
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_all_audits(0usize, 10usize, no_kind())
+        .returns(ExpectError(4, "Start index out of bounds"))
+        .run();
+
+    for hash_byte in 1..=3u8 {
+        state.register_audit(AUDITOR, hash_byte, 3, "audit");
+    }
+    // A second audit of the first contract does not add a new entry
+    state.register_audit(OTHER_AUDITOR, 1, 5, "newer audit");
+
+    assert_eq!(
+        all_audits(&mut state, 0, 10),
+        vec![contract_hash(1), contract_hash(2), contract_hash(3)]
+    );
+    assert_eq!(all_audits(&mut state, 1, 1), vec![contract_hash(2)]);
+    assert_eq!(all_audits(&mut state, 2, 10), vec![contract_hash(3)]);
+    assert!(all_audits(&mut state, 0, 0).is_empty());
+
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_all_audits(3usize, 1usize, no_kind())
+        .returns(ExpectError(4, "Start index out of bounds"))
+        .run();
+
+    let (_, stars, _, auditor, _, _) = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_all_audits(0usize, 1usize, no_kind())
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .next()
+        .unwrap()
+        .into_tuple();
+    assert_eq!(stars, 5);
+    assert_eq!(auditor, OTHER_AUDITOR.to_managed_address());
+}
+
+#[test]
+fn get_latest_audit_on_missing_hash() {
+    let mut state = RegistryTestState::new();
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_latest_audit(contract_hash(9))
+        .returns(ExpectError(4, "No audits found for this contract"))
+        .run();
+
+    state.register_audit(AUDITOR, 1, 3, "audit");
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_latest_audit(contract_hash(9))
+        .returns(ExpectError(4, "No audits found for this contract"))
+        .run();
+}
+
+#[test]
+fn withdraw_by_non_owner() {
+    let mut state = RegistryTestState::new();
+    state.run_admin_action(proxy::AdminAction::SetRegistrationFee(BigUint::from(REGISTRATION_FEE)));
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audit(contract_hash(1), 3u8, ManagedBuffer::from("paid"))
+        .egld(REGISTRATION_FEE)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .queue_admin_action(proxy::AdminAction::Withdraw)
+        .returns(ExpectError(4, "Only owner can manage admin actions"))
+        .run();
+
+    // A withdrawal queued by the owner can only be executed by the owner
+    let action_id = state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .queue_admin_action(proxy::AdminAction::Withdraw)
+        .returns(ReturnsResult)
+        .run();
+    state.advance_time(TIMELOCK_DELAY);
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .execute_admin_action(action_id)
+        .returns(ExpectError(4, "Only owner can manage admin actions"))
+        .run();
+    state.world.check_account(REGISTRY).balance(REGISTRATION_FEE);
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .execute_admin_action(action_id)
+        .run();
+    state
+        .world
+        .check_account(OWNER)
+        .balance(INITIAL_BALANCE + REGISTRATION_FEE);
+}
+
+#[test]
+fn register_audit_emits_event() {
+    let mut state = RegistryTestState::new();
+    let logs = state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audit(contract_hash(1), 4u8, ManagedBuffer::from("good"))
+        .returns(ReturnsLogs)
+        .run();
+
+    assert_eq!(logs.len(), 1);
+    let log = &logs[0];
+    assert_eq!(log.address, REGISTRY.to_address());
+    assert_eq!(log.endpoint, "register_audit");
+    assert_eq!(
+        log.topics,
+        vec![
+            b"auditRegistered".to_vec(),
+            vec![1u8; 32],
+            vec![4],
+            AUDITOR.to_address().to_vec(),
+            vec![],
+            vec![1],
+            vec![1],
+            START_TIMESTAMP.to_be_bytes()[6..].to_vec(),
+        ]
+    );
+    assert_eq!(log.data, vec![b"good".to_vec()]);
+}
+
+#[test]
+fn auditor_history_is_deduplicated() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 3, "first");
+    state.register_audit(AUDITOR, 2, 4, "second contract");
+    state.register_audit(AUDITOR, 1, 5, "re-audit");
+    state.register_audit(OTHER_AUDITOR, 1, 2, "second opinion");
+
+    assert_eq!(
+        auditor_history(&mut state, AUDITOR),
+        vec![contract_hash(1), contract_hash(2)]
+    );
+    assert_eq!(auditor_history(&mut state, OTHER_AUDITOR), vec![contract_hash(1)]);
+    assert!(auditor_history(&mut state, THIRD_AUDITOR).is_empty());
+
+    let audits = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contract_audits(contract_hash(1), no_kind())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(audits.into_iter().count(), 3);
+}
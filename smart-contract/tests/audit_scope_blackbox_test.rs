@@ -0,0 +1,135 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const REPOSITORY_URL: &str = "https://github.com/example/protocol";
+
+type ScopeFileArg = MultiValue2<ManagedBuffer<StaticApi>, ManagedByteArray<StaticApi, 32>>;
+
+fn scope_files(paths: &[&[u8]]) -> MultiValueEncoded<StaticApi, ScopeFileArg> {
+    let mut files = MultiValueEncoded::new();
+    for (position, path) in paths.iter().enumerate() {
+        files.push((ManagedBuffer::new_from_bytes(path), contract_hash(position as u8)).into());
+    }
+    files
+}
+
+fn set_scope_expect_error(
+    state: &mut RegistryTestState,
+    from: TestAddress,
+    repository_url: &[u8],
+    commit_hash: &[u8],
+    files: &[&[u8]],
+    message: &str,
+) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .set_audit_scope(
+            contract_hash(1),
+            1usize,
+            ManagedBuffer::new_from_bytes(repository_url),
+            ManagedBuffer::new_from_bytes(commit_hash),
+            ManagedBuffer::from("multiversx-sc 0.56.1"),
+            scope_files(files),
+        )
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+#[test]
+fn auditor_sets_the_scope_once() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "audit");
+
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .set_audit_scope(
+            contract_hash(1),
+            1usize,
+            ManagedBuffer::from(REPOSITORY_URL),
+            ManagedBuffer::new_from_bytes(&[0xab; 20]),
+            ManagedBuffer::from("multiversx-sc 0.56.1"),
+            scope_files(&[b"src/lib.rs", b"src/storage.rs"]),
+        )
+        .run();
+
+    let scope = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_audit_scope(contract_hash(1), 1usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert_eq!(scope.repository_url, ManagedBuffer::from(REPOSITORY_URL));
+    assert_eq!(scope.files.len(), 2);
+    assert_eq!(scope.files.get(1).path, ManagedBuffer::from("src/storage.rs"));
+
+    set_scope_expect_error(&mut state, AUDITOR, REPOSITORY_URL.as_bytes(), &[0xab; 20], &[], "Audit scope already set");
+}
+
+#[test]
+fn only_the_auditor_can_set_the_scope() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "audit");
+
+    set_scope_expect_error(
+        &mut state,
+        OTHER_AUDITOR,
+        REPOSITORY_URL.as_bytes(),
+        &[0xab; 20],
+        &[],
+        "Only the auditor can set the audit scope",
+    );
+
+    let scope = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_audit_scope(contract_hash(1), 1usize)
+        .returns(ReturnsResult)
+        .run();
+    assert!(scope.into_option().is_none());
+}
+
+#[test]
+fn scope_metadata_is_size_limited() {
+    let mut state = RegistryTestState::new();
+    set_scope_expect_error(&mut state, AUDITOR, REPOSITORY_URL.as_bytes(), &[0xab; 20], &[], "Audit not found");
+
+    state.register_audit(AUDITOR, 1, 4, "audit");
+    set_scope_expect_error(&mut state, AUDITOR, b"", &[0xab; 20], &[], "Repository URL cannot be empty");
+    set_scope_expect_error(&mut state, AUDITOR, &[b'u'; 257], &[0xab; 20], &[], "Repository URL too long");
+    set_scope_expect_error(&mut state, AUDITOR, REPOSITORY_URL.as_bytes(), &[0xab; 40], &[], "Invalid commit hash");
+    set_scope_expect_error(&mut state, AUDITOR, REPOSITORY_URL.as_bytes(), &[0xab; 32], &[b""], "File path cannot be empty");
+    set_scope_expect_error(
+        &mut state,
+        AUDITOR,
+        REPOSITORY_URL.as_bytes(),
+        &[0xab; 32],
+        &[&[b'p'; 257]],
+        "File path too long",
+    );
+
+    let too_many_files = [&b"src/lib.rs"[..]; 101];
+    set_scope_expect_error(
+        &mut state,
+        AUDITOR,
+        REPOSITORY_URL.as_bytes(),
+        &[0xab; 32],
+        &too_many_files,
+        "Too many scope files",
+    );
+}
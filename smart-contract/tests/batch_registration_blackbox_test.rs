@@ -0,0 +1,125 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+type BatchEntry = MultiValue3<ManagedByteArray<StaticApi, 32>, u8, ManagedBuffer<StaticApi>>;
+
+fn batch(entries: &[(u8, u8, &str)]) -> MultiValueEncoded<StaticApi, BatchEntry> {
+    let mut audits = MultiValueEncoded::new();
+    for &(hash_byte, stars, summary) in entries {
+        audits.push((contract_hash(hash_byte), stars, ManagedBuffer::from(summary)).into());
+    }
+    audits
+}
+
+fn register_batch_expect_error(state: &mut RegistryTestState, entries: &[(u8, u8, &str)], message: &str) {
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audits_batch(batch(entries))
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+#[test]
+fn batch_registers_every_entry() {
+    let mut state = RegistryTestState::new();
+    let logs = state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audits_batch(batch(&[(1, 4, "first"), (2, 3, "second"), (1, 5, "re-audit")]))
+        .returns(ReturnsLogs)
+        .run();
+
+    // One auditRegistered per entry, then the batch summary
+    let batch_log = logs.last().unwrap();
+    assert_eq!(batch_log.topics[0], b"auditBatchRegistered".to_vec());
+    assert_eq!(batch_log.topics[2], vec![3]);
+
+    let total_contracts = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_total_contracts()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(total_contracts, 2);
+
+    // Repeated hashes from the same caller are recorded once in the auditor history
+    let history: Vec<_> = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_auditor_history(AUDITOR.to_managed_address())
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(history, vec![contract_hash(1), contract_hash(2)]);
+
+    let latest = state.latest_audit(1);
+    assert_eq!(latest.stars, 5);
+    assert_eq!(latest.summary, ManagedBuffer::from("re-audit"));
+}
+
+#[test]
+fn batch_rejects_empty_and_oversized_batches() {
+    let mut state = RegistryTestState::new();
+    register_batch_expect_error(&mut state, &[], "Batch cannot be empty");
+
+    let oversized: Vec<(u8, u8, &str)> = (0..=50u8).map(|hash_byte| (hash_byte, 3, "audit")).collect();
+    register_batch_expect_error(&mut state, &oversized, "Batch too large");
+}
+
+#[test]
+fn batch_is_all_or_nothing() {
+    let mut state = RegistryTestState::new();
+    register_batch_expect_error(&mut state, &[(1, 4, "valid"), (2, 6, "invalid")], "Stars must be between 0 and 5");
+    register_batch_expect_error(&mut state, &[(1, 4, "valid"), (2, 3, "")], "Summary cannot be empty");
+
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_latest_audit(contract_hash(1))
+        .returns(ExpectError(4, "No audits found for this contract"))
+        .run();
+}
+
+#[test]
+fn batch_charges_the_fee_per_entry() {
+    let mut state = RegistryTestState::new();
+    state.run_admin_action(proxy::AdminAction::SetRegistrationFee(BigUint::from(10u64)));
+
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audits_batch(batch(&[(1, 4, "first"), (2, 3, "second")]))
+        .egld(10u64)
+        .returns(ExpectError(4, "Incorrect registration fee"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audits_batch(batch(&[(1, 4, "first"), (2, 3, "second")]))
+        .egld(20u64)
+        .run();
+    state.world.check_account(REGISTRY).balance(20u64);
+}
@@ -0,0 +1,398 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const BOUNTY_DURATION: u64 = 86_400;
+const POOL_FUNDS: u64 = 1_000;
+
+fn report_hash(byte: u8) -> ManagedByteArray<StaticApi, 32> {
+    ManagedByteArray::new_from_bytes(&[byte; 32])
+}
+
+fn create_bounty(state: &mut RegistryTestState, from: TestAddress, low_reward: u64, funds: u64) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .create_bounty(contract_hash(1), BOUNTY_DURATION, low_reward, 100u64, 300u64, 800u64)
+        .egld(funds)
+        .run();
+}
+
+fn create_bounty_expect_error(
+    state: &mut RegistryTestState,
+    from: TestAddress,
+    duration: u64,
+    rewards: [u64; 4],
+    funds: u64,
+    message: &str,
+) {
+    let [low_reward, medium_reward, high_reward, critical_reward] = rewards;
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .create_bounty(contract_hash(1), duration, low_reward, medium_reward, high_reward, critical_reward)
+        .egld(funds)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn submit_report(state: &mut RegistryTestState, reporter: TestAddress, byte: u8, severity: proxy::Severity) {
+    state
+        .world
+        .tx()
+        .from(reporter)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .submit_vulnerability_report(contract_hash(1), report_hash(byte), severity)
+        .run();
+}
+
+fn bounty_reports(state: &mut RegistryTestState) -> Vec<proxy::VulnerabilityReport<StaticApi>> {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_bounty_reports(contract_hash(1))
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn create_bounty_requires_the_linked_project_owner() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "audit");
+    create_bounty_expect_error(&mut state, USER, BOUNTY_DURATION, [10, 100, 300, 800], POOL_FUNDS, "Project not linked");
+
+    state.link_project(1);
+    create_bounty_expect_error(
+        &mut state,
+        USER,
+        BOUNTY_DURATION,
+        [10, 100, 300, 800],
+        POOL_FUNDS,
+        "Only the project owner can create a bounty",
+    );
+
+    create_bounty(&mut state, PROJECT_OWNER, 10, POOL_FUNDS);
+    state.world.check_account(REGISTRY).balance(POOL_FUNDS);
+    create_bounty_expect_error(
+        &mut state,
+        PROJECT_OWNER,
+        BOUNTY_DURATION,
+        [10, 100, 300, 800],
+        POOL_FUNDS,
+        "Bounty already exists",
+    );
+}
+
+#[test]
+fn create_bounty_validates_terms() {
+    let mut state = RegistryTestState::new();
+    create_bounty_expect_error(
+        &mut state,
+        PROJECT_OWNER,
+        BOUNTY_DURATION,
+        [10, 100, 300, 800],
+        POOL_FUNDS,
+        "No audits found for this contract",
+    );
+
+    state.link_project(1);
+    create_bounty_expect_error(&mut state, PROJECT_OWNER, 60, [10, 100, 300, 800], POOL_FUNDS, "Invalid bounty duration");
+    create_bounty_expect_error(
+        &mut state,
+        PROJECT_OWNER,
+        BOUNTY_DURATION,
+        [0, 0, 0, 0],
+        POOL_FUNDS,
+        "Critical reward cannot be zero",
+    );
+    create_bounty_expect_error(
+        &mut state,
+        PROJECT_OWNER,
+        BOUNTY_DURATION,
+        [10, 300, 100, 800],
+        POOL_FUNDS,
+        "Rewards must increase with severity",
+    );
+    create_bounty_expect_error(&mut state, PROJECT_OWNER, BOUNTY_DURATION, [10, 100, 300, 800], 0, "Bounty must be funded");
+}
+
+#[test]
+fn approved_report_is_paid_from_the_pool() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    create_bounty(&mut state, PROJECT_OWNER, 10, POOL_FUNDS);
+
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .submit_vulnerability_report(contract_hash(1), report_hash(7), proxy::Severity::High)
+        .returns(ExpectError(4, "Project cannot report on its own bounty"))
+        .run();
+    submit_report(&mut state, USER, 7, proxy::Severity::Critical);
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .approve_report(contract_hash(1), 1usize, proxy::Severity::High)
+        .returns(ExpectError(4, "Only the project can manage its bounty"))
+        .run();
+
+    // The project settles on a lower severity than reported
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .approve_report(contract_hash(1), 1usize, proxy::Severity::High)
+        .run();
+    state.world.check_account(USER).balance(INITIAL_BALANCE + 300);
+    state.world.check_account(REGISTRY).balance(POOL_FUNDS - 300);
+
+    let reports = bounty_reports(&mut state);
+    assert_eq!(reports[0].status, proxy::ReportStatus::Paid);
+    assert_eq!(reports[0].payout, BigUint::from(300u64));
+
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .reject_report(contract_hash(1), 1usize)
+        .returns(ExpectError(4, "Report already processed"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .reject_report(contract_hash(1), 2usize)
+        .returns(ExpectError(4, "Report not found"))
+        .run();
+}
+
+#[test]
+fn anyone_can_top_up_an_open_bounty() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .fund_bounty(contract_hash(1))
+        .egld(200u64)
+        .returns(ExpectError(4, "No bounty for this contract"))
+        .run();
+
+    create_bounty(&mut state, PROJECT_OWNER, 10, POOL_FUNDS);
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .fund_bounty(contract_hash(1))
+        .egld(200u64)
+        .run();
+
+    let pool = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_bounty_pool(contract_hash(1))
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert_eq!(pool.balance, BigUint::from(POOL_FUNDS + 200));
+    state.world.check_account(REGISTRY).balance(POOL_FUNDS + 200);
+
+    let active_bounties: Vec<_> = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .bounty_contracts()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(active_bounties, vec![contract_hash(1)]);
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .fund_bounty(contract_hash(1))
+        .egld(0u64)
+        .returns(ExpectError(4, "Bounty must be funded"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .fund_bounty(contract_hash(1))
+        .single_esdt(&GOVERNANCE_TOKEN.into(), 0, &BigUint::from(200u64))
+        .returns(ExpectError(4, "Wrong bounty token"))
+        .run();
+
+    state.advance_time(BOUNTY_DURATION);
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .fund_bounty(contract_hash(1))
+        .egld(200u64)
+        .returns(ExpectError(4, "Bounty has expired"))
+        .run();
+}
+
+#[test]
+fn payouts_are_capped_by_the_pool_balance() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    create_bounty(&mut state, PROJECT_OWNER, 10, 500);
+    submit_report(&mut state, USER, 1, proxy::Severity::Critical);
+    submit_report(&mut state, USER, 2, proxy::Severity::Low);
+
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .approve_report(contract_hash(1), 1usize, proxy::Severity::Critical)
+        .run();
+    state.world.check_account(USER).balance(INITIAL_BALANCE + 500);
+
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .approve_report(contract_hash(1), 2usize, proxy::Severity::Low)
+        .returns(ExpectError(4, "Bounty pool is empty"))
+        .run();
+}
+
+#[test]
+fn approve_report_rejects_tiers_without_reward() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    create_bounty(&mut state, PROJECT_OWNER, 0, POOL_FUNDS);
+    submit_report(&mut state, USER, 1, proxy::Severity::Low);
+
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .approve_report(contract_hash(1), 1usize, proxy::Severity::Low)
+        .returns(ExpectError(4, "No reward for this severity"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .reject_report(contract_hash(1), 1usize)
+        .run();
+    assert_eq!(bounty_reports(&mut state)[0].status, proxy::ReportStatus::Rejected);
+}
+
+#[test]
+fn closed_bounty_returns_funds_and_leaves_reports_behind() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    create_bounty(&mut state, PROJECT_OWNER, 10, POOL_FUNDS);
+    submit_report(&mut state, USER, 1, proxy::Severity::Medium);
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .close_bounty(contract_hash(1))
+        .returns(ExpectError(4, "Bounty is still open"))
+        .run();
+
+    state.advance_time(BOUNTY_DURATION);
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .submit_vulnerability_report(contract_hash(1), report_hash(2), proxy::Severity::Low)
+        .returns(ExpectError(4, "Bounty has expired"))
+        .run();
+
+    // Anyone can close an expired pool; the balance goes back to the project
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .close_bounty(contract_hash(1))
+        .run();
+    state.world.check_account(PROJECT_OWNER).balance(INITIAL_BALANCE);
+    state.world.check_account(REGISTRY).balance(0u64);
+
+    // The pending report stays with the closed pool
+    create_bounty(&mut state, PROJECT_OWNER, 10, POOL_FUNDS);
+    assert!(bounty_reports(&mut state).is_empty());
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .approve_report(contract_hash(1), 1usize, proxy::Severity::Medium)
+        .returns(ExpectError(4, "Report not found"))
+        .run();
+
+    let closed_pool_reports = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_bounty_pool_reports(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(closed_pool_reports.into_iter().count(), 1);
+}
@@ -0,0 +1,187 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const EVM_CHAIN: &str = "creator-network";
+
+fn no_provenance() -> OptionalValue<proxy::AuditProvenance<StaticApi>> {
+    OptionalValue::None
+}
+
+fn register_chain_audit(
+    state: &mut RegistryTestState,
+    chain_id: &[u8],
+    hash_byte: u8,
+    stars: u8,
+) -> ManagedByteArray<StaticApi, 32> {
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_chain_audit(
+            ManagedBuffer::new_from_bytes(chain_id),
+            proxy::HashAlgorithm::EvmBytecodeKeccak256,
+            contract_hash(hash_byte),
+            stars,
+            ManagedBuffer::from("evm audit"),
+            no_provenance(),
+        )
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn chain_audits_are_stored_under_a_derived_key() {
+    let mut state = RegistryTestState::new();
+    let contract_key = register_chain_audit(&mut state, EVM_CHAIN.as_bytes(), 1, 4);
+    assert_ne!(contract_key, contract_hash(1));
+
+    let expected_key = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contract_key(
+            ManagedBuffer::from(EVM_CHAIN),
+            proxy::HashAlgorithm::EvmBytecodeKeccak256,
+            contract_hash(1),
+        )
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(contract_key, expected_key);
+
+    let namespace = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contract_namespace(contract_key.clone())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(namespace.chain_id, ManagedBuffer::from(EVM_CHAIN));
+    assert_eq!(namespace.hash_algorithm, proxy::HashAlgorithm::EvmBytecodeKeccak256);
+    assert_eq!(namespace.contract_hash, contract_hash(1));
+
+    let audit = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_latest_audit(contract_key)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(audit.chain_id, ManagedBuffer::from(EVM_CHAIN));
+    assert_eq!(audit.hash_algorithm, proxy::HashAlgorithm::EvmBytecodeKeccak256);
+}
+
+#[test]
+fn legacy_endpoint_keeps_the_raw_hash() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "multiversx audit");
+
+    let contract_key = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contract_key(
+            ManagedBuffer::from("multiversx"),
+            proxy::HashAlgorithm::MultiversXCodeHash,
+            contract_hash(1),
+        )
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(contract_key, contract_hash(1));
+
+    let audit = state.latest_audit(1);
+    assert_eq!(audit.chain_id, ManagedBuffer::from("multiversx"));
+    assert_eq!(audit.hash_algorithm, proxy::HashAlgorithm::MultiversXCodeHash);
+}
+
+#[test]
+fn views_filter_by_chain() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "multiversx audit");
+    let evm_key = register_chain_audit(&mut state, EVM_CHAIN.as_bytes(), 1, 3);
+    register_chain_audit(&mut state, EVM_CHAIN.as_bytes(), 2, 5);
+
+    let total_evm_contracts = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_total_contracts_by_chain(ManagedBuffer::from(EVM_CHAIN))
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(total_evm_contracts, 2);
+
+    let evm_page: Vec<_> = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_all_audits_by_chain(ManagedBuffer::from(EVM_CHAIN), 0usize, 1usize, OptionalValue::<proxy::AuditKind>::None)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|item| item.into_tuple().0)
+        .collect();
+    assert_eq!(evm_page, vec![evm_key]);
+
+    let known_chains = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .known_chains()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(known_chains.into_iter().count(), 2);
+
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_all_audits_by_chain(ManagedBuffer::from("unknown"), 0usize, 1usize, OptionalValue::<proxy::AuditKind>::None)
+        .returns(ExpectError(4, "Start index out of bounds"))
+        .run();
+}
+
+#[test]
+fn chain_id_is_validated() {
+    let mut state = RegistryTestState::new();
+    for (chain_id, message) in [
+        (&b""[..], "Chain id cannot be empty"),
+        (&[b'c'; 33][..], "Chain id too long"),
+    ] {
+        state
+            .world
+            .tx()
+            .from(AUDITOR)
+            .to(REGISTRY)
+            .typed(proxy::AuditRegistryProxy)
+            .register_chain_audit(
+                ManagedBuffer::new_from_bytes(chain_id),
+                proxy::HashAlgorithm::SourceHash,
+                contract_hash(1),
+                4u8,
+                ManagedBuffer::from("audit"),
+                no_provenance(),
+            )
+            .returns(ExpectError(4, message))
+            .run();
+    }
+
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contract_namespace(contract_hash(1))
+        .returns(ExpectError(4, "No audits found for this contract"))
+        .run();
+}
@@ -0,0 +1,256 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::{imports::*, multiversx_chain_vm::crypto_functions::keccak256};
+use smart_contract::commitment::AuditReveal;
+
+const DISCLOSURE_DELAY: u64 = 86_400;
+const SALT: [u8; 32] = [7; 32];
+
+// Same keccak256 of the top-encoded reveal that publish_audit checks
+fn commitment_of(auditor: TestAddress, stars: u8, summary: &str) -> ManagedByteArray<StaticApi, 32> {
+    let reveal = AuditReveal::<StaticApi> {
+        auditor: auditor.to_managed_address(),
+        contract_hash: contract_hash(1),
+        stars,
+        summary: ManagedBuffer::from(summary),
+        salt: ManagedByteArray::new_from_bytes(&SALT),
+    };
+    let mut encoded = ManagedBuffer::<StaticApi>::new();
+    reveal.top_encode(&mut encoded).unwrap();
+
+    ManagedByteArray::new_from_bytes(&keccak256(&encoded.to_boxed_bytes().into_vec()))
+}
+
+fn commit(state: &mut RegistryTestState, stars: u8, summary: &str) -> u64 {
+    let disclosure_time = state.timestamp + DISCLOSURE_DELAY;
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .commit_audit(
+            contract_hash(1),
+            commitment_of(AUDITOR, stars, summary),
+            disclosure_time,
+        )
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn commit_expect_error(state: &mut RegistryTestState, disclosure_time: u64, message: &str) {
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .commit_audit(contract_hash(1), commitment_of(AUDITOR, 4, "findings"), disclosure_time)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn publish(state: &mut RegistryTestState, commitment_id: u64, stars: u8, summary: &str) -> usize {
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .publish_audit(
+            commitment_id,
+            stars,
+            ManagedBuffer::from(summary),
+            ManagedByteArray::new_from_bytes(&SALT),
+        )
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn publish_expect_error(
+    state: &mut RegistryTestState,
+    from: TestAddress,
+    commitment_id: u64,
+    stars: u8,
+    summary: &str,
+    message: &str,
+) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .publish_audit(
+            commitment_id,
+            stars,
+            ManagedBuffer::from(summary),
+            ManagedByteArray::new_from_bytes(&SALT),
+        )
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn pending_commitments(state: &mut RegistryTestState) -> Vec<u64> {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_pending_commitments(contract_hash(1))
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+#[test]
+fn committed_audit_is_published_after_disclosure() {
+    let mut state = RegistryTestState::new();
+    let commitment_id = commit(&mut state, 4, "findings");
+    assert_eq!(pending_commitments(&mut state), vec![commitment_id]);
+
+    state.advance_time(DISCLOSURE_DELAY);
+    let audit_index = publish(&mut state, commitment_id, 4, "findings");
+    assert_eq!(audit_index, 1);
+    assert_eq!(pending_commitments(&mut state), Vec::<u64>::new());
+
+    let audit = state.latest_audit(1);
+    assert_eq!(audit.stars, 4);
+    assert_eq!(audit.summary, ManagedBuffer::from("findings"));
+    assert_eq!(audit.timestamp, state.timestamp);
+
+    let published = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_published_commitment(contract_hash(1), 1usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert_eq!(published.commitment, commitment_of(AUDITOR, 4, "findings"));
+    assert_eq!(published.committed_at, START_TIMESTAMP);
+}
+
+#[test]
+fn commitments_are_validated() {
+    let mut state = RegistryTestState::new();
+    commit_expect_error(&mut state, START_TIMESTAMP, "Invalid disclosure time");
+    commit_expect_error(&mut state, START_TIMESTAMP + 31_536_001, "Invalid disclosure time");
+
+    let commitment_id = commit(&mut state, 4, "findings");
+    publish_expect_error(
+        &mut state,
+        AUDITOR,
+        commitment_id + 1,
+        4,
+        "findings",
+        "Commitment not found",
+    );
+    publish_expect_error(
+        &mut state,
+        OTHER_AUDITOR,
+        commitment_id,
+        4,
+        "findings",
+        "Only the auditor can publish",
+    );
+    publish_expect_error(
+        &mut state,
+        AUDITOR,
+        commitment_id,
+        4,
+        "findings",
+        "Disclosure time not reached",
+    );
+
+    state.advance_time(DISCLOSURE_DELAY);
+    publish_expect_error(
+        &mut state,
+        AUDITOR,
+        commitment_id,
+        6,
+        "findings",
+        "Stars must be between 0 and 5",
+    );
+    publish_expect_error(
+        &mut state,
+        AUDITOR,
+        commitment_id,
+        5,
+        "findings",
+        "Content does not match the commitment",
+    );
+    publish_expect_error(
+        &mut state,
+        AUDITOR,
+        commitment_id,
+        4,
+        "edited",
+        "Content does not match the commitment",
+    );
+
+    publish(&mut state, commitment_id, 4, "findings");
+    publish_expect_error(
+        &mut state,
+        AUDITOR,
+        commitment_id,
+        4,
+        "findings",
+        "Commitment not found",
+    );
+}
+
+#[test]
+fn published_audits_cannot_be_amended() {
+    let mut state = RegistryTestState::new();
+    let commitment_id = commit(&mut state, 4, "findings");
+    state.advance_time(DISCLOSURE_DELAY);
+    publish(&mut state, commitment_id, 4, "findings");
+
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .amend_audit(contract_hash(1), 1usize, 1u8, ManagedBuffer::from("rewritten"))
+        .returns(ExpectError(4, "Commit-reveal audits cannot be amended"))
+        .run();
+    assert_eq!(state.latest_audit(1).summary, ManagedBuffer::from("findings"));
+}
+
+#[test]
+fn copied_commitments_cannot_be_published_by_another_auditor() {
+    let mut state = RegistryTestState::new();
+    let commitment_id = commit(&mut state, 4, "findings");
+
+    // The copy is scheduled to disclose first, but the hash is bound to the original auditor
+    let copied_id = state
+        .world
+        .tx()
+        .from(OTHER_AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .commit_audit(
+            contract_hash(1),
+            commitment_of(AUDITOR, 4, "findings"),
+            START_TIMESTAMP + 1,
+        )
+        .returns(ReturnsResult)
+        .run();
+
+    state.advance_time(DISCLOSURE_DELAY);
+    publish(&mut state, commitment_id, 4, "findings");
+    publish_expect_error(
+        &mut state,
+        OTHER_AUDITOR,
+        copied_id,
+        4,
+        "findings",
+        "Content does not match the commitment",
+    );
+    assert_eq!(pending_commitments(&mut state), vec![copied_id]);
+}
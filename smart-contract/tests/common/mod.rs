@@ -0,0 +1,126 @@
+// Shared setup for the per-module blackbox tests; not every test file uses every helper
+#![allow(dead_code)]
+
+#[path = "../../output/proxy.rs"]
+pub mod proxy;
+
+use multiversx_sc_scenario::imports::*;
+
+pub const CODE_PATH: MxscPath = MxscPath::new("output/smart-contract.mxsc.json");
+
+pub const OWNER: TestAddress = TestAddress::new("owner");
+pub const AUDITOR: TestAddress = TestAddress::new("auditor");
+pub const OTHER_AUDITOR: TestAddress = TestAddress::new("other-auditor");
+pub const THIRD_AUDITOR: TestAddress = TestAddress::new("third-auditor");
+pub const PROJECT_OWNER: TestAddress = TestAddress::new("project-owner");
+pub const USER: TestAddress = TestAddress::new("user");
+pub const REGISTRY: TestSCAddress = TestSCAddress::new("registry");
+pub const PROJECT_SC: TestSCAddress = TestSCAddress::new("project");
+
+pub const GOVERNANCE_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("GOV-123456");
+
+pub const START_TIMESTAMP: u64 = 1_000;
+pub const TIMELOCK_DELAY: u64 = 172_800;
+pub const INITIAL_BALANCE: u64 = 1_000_000;
+
+pub fn contract_hash(byte: u8) -> ManagedByteArray<StaticApi, 32> {
+    ManagedByteArray::new_from_bytes(&[byte; 32])
+}
+
+pub struct RegistryTestState {
+    pub world: ScenarioWorld,
+    pub timestamp: u64,
+}
+
+impl RegistryTestState {
+    pub fn new() -> Self {
+        let mut world = ScenarioWorld::new();
+        world.register_contract(CODE_PATH, smart_contract::ContractBuilder);
+
+        for account in [OWNER, AUDITOR, OTHER_AUDITOR, THIRD_AUDITOR, PROJECT_OWNER, USER] {
+            world
+                .account(account)
+                .nonce(1)
+                .balance(INITIAL_BALANCE)
+                .esdt_balance(GOVERNANCE_TOKEN, INITIAL_BALANCE);
+        }
+        world.current_block().block_timestamp(START_TIMESTAMP);
+
+        world
+            .tx()
+            .from(OWNER)
+            .typed(proxy::AuditRegistryProxy)
+            .init()
+            .code(CODE_PATH)
+            .new_address(REGISTRY)
+            .run();
+
+        Self {
+            world,
+            timestamp: START_TIMESTAMP,
+        }
+    }
+
+    pub fn advance_time(&mut self, seconds: u64) {
+        self.timestamp += seconds;
+        self.world.current_block().block_timestamp(self.timestamp);
+    }
+
+    pub fn register_audit(&mut self, auditor: TestAddress, hash_byte: u8, stars: u8, summary: &str) {
+        self.world
+            .tx()
+            .from(auditor)
+            .to(REGISTRY)
+            .typed(proxy::AuditRegistryProxy)
+            .register_audit(contract_hash(hash_byte), stars, ManagedBuffer::from(summary))
+            .run();
+    }
+
+    // Queues an admin action and executes it once the default delay has elapsed
+    pub fn run_admin_action(&mut self, action: proxy::AdminAction<StaticApi>) {
+        let action_id = self
+            .world
+            .tx()
+            .from(OWNER)
+            .to(REGISTRY)
+            .typed(proxy::AuditRegistryProxy)
+            .queue_admin_action(action)
+            .returns(ReturnsResult)
+            .run();
+        self.advance_time(TIMELOCK_DELAY);
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(REGISTRY)
+            .typed(proxy::AuditRegistryProxy)
+            .execute_admin_action(action_id)
+            .run();
+    }
+
+    // Registers an audit of PROJECT_SC's hash and links the contract to PROJECT_OWNER
+    pub fn link_project(&mut self, hash_byte: u8) {
+        self.world.account(PROJECT_SC).nonce(1).code(CODE_PATH);
+        self.register_audit(AUDITOR, hash_byte, 4, "independent audit");
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(REGISTRY)
+            .typed(proxy::AuditRegistryProxy)
+            .link_project(
+                contract_hash(hash_byte),
+                PROJECT_SC.to_managed_address(),
+                PROJECT_OWNER.to_managed_address(),
+            )
+            .run();
+    }
+
+    pub fn latest_audit(&mut self, hash_byte: u8) -> proxy::Audit<StaticApi> {
+        self.world
+            .query()
+            .to(REGISTRY)
+            .typed(proxy::AuditRegistryProxy)
+            .get_latest_audit(contract_hash(hash_byte))
+            .returns(ReturnsResult)
+            .run()
+    }
+}
@@ -0,0 +1,204 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const VOTING_PERIOD: u64 = 604_800;
+const MAX_VOTING_PERIOD: u64 = 2_592_000;
+
+fn set_governance_token(state: &mut RegistryTestState) {
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .set_governance_token(GOVERNANCE_TOKEN)
+        .run();
+}
+
+fn vote(state: &mut RegistryTestState, voter: TestAddress, hash_byte: u8, upvote: bool, amount: u64) {
+    state
+        .world
+        .tx()
+        .from(voter)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .vote_on_audit(contract_hash(hash_byte), 1usize, upvote)
+        .payment(TestEsdtTransfer(GOVERNANCE_TOKEN, 0, amount))
+        .run();
+}
+
+fn vote_expect_error(state: &mut RegistryTestState, upvote: bool, message: &str) {
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .vote_on_audit(contract_hash(1), 1usize, upvote)
+        .payment(TestEsdtTransfer(GOVERNANCE_TOKEN, 0, 10u64))
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn window_end(state: &mut RegistryTestState, hash_byte: u8) -> u64 {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_voting_window_end(contract_hash(hash_byte), 1usize)
+        .returns(ReturnsResult)
+        .run()
+}
+
+// Voting enabled with a one week period, and contract 1 audited once the period applies
+fn setup() -> (RegistryTestState, u64) {
+    let mut state = RegistryTestState::new();
+    set_governance_token(&mut state);
+    state.run_admin_action(proxy::AdminAction::SetVotingPeriod(VOTING_PERIOD));
+    state.register_audit(AUDITOR, 1, 4, "audit");
+    let audited_at = state.timestamp;
+    (state, audited_at)
+}
+
+#[test]
+fn votes_are_tallied_and_unlocked_after_the_window() {
+    let (mut state, audited_at) = setup();
+    vote(&mut state, USER, 1, true, 300);
+    vote(&mut state, USER, 1, true, 200);
+    vote(&mut state, PROJECT_OWNER, 1, false, 100);
+
+    let tally = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_audit_votes(contract_hash(1), 1usize)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(tally.upvotes, BigUint::from(500u64));
+    assert_eq!(tally.downvotes, BigUint::from(100u64));
+    state
+        .world
+        .check_account(USER)
+        .esdt_balance(GOVERNANCE_TOKEN, INITIAL_BALANCE - 500);
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unlock_votes(contract_hash(1), 1usize)
+        .returns(ExpectError(4, "Voting window still open"))
+        .run();
+
+    state.advance_time(audited_at + VOTING_PERIOD - state.timestamp);
+    vote_expect_error(&mut state, true, "Voting window closed");
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unlock_votes(contract_hash(1), 1usize)
+        .run();
+    state
+        .world
+        .check_account(USER)
+        .esdt_balance(GOVERNANCE_TOKEN, INITIAL_BALANCE);
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unlock_votes(contract_hash(1), 1usize)
+        .returns(ExpectError(4, "No locked votes"))
+        .run();
+}
+
+#[test]
+fn first_vote_fixes_the_voting_window() {
+    let (mut state, audited_at) = setup();
+    state.register_audit(AUDITOR, 2, 4, "unvoted audit");
+    let second_audited_at = state.timestamp;
+    vote(&mut state, USER, 1, true, 100);
+
+    state.run_admin_action(proxy::AdminAction::SetVotingPeriod(MAX_VOTING_PERIOD));
+    assert_eq!(window_end(&mut state, 1), audited_at + VOTING_PERIOD);
+    // Audits without votes still follow the current period
+    assert_eq!(window_end(&mut state, 2), second_audited_at + MAX_VOTING_PERIOD);
+}
+
+#[test]
+fn voting_period_is_bounded() {
+    let mut state = RegistryTestState::new();
+    for voting_period in [0, MAX_VOTING_PERIOD + 1, u64::MAX] {
+        state
+            .world
+            .tx()
+            .from(OWNER)
+            .to(REGISTRY)
+            .typed(proxy::AuditRegistryProxy)
+            .queue_admin_action(proxy::AdminAction::SetVotingPeriod(voting_period))
+            .returns(ExpectError(4, "Voting period out of range"))
+            .run();
+    }
+}
+
+#[test]
+fn vote_rejections() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "audit");
+    vote_expect_error(&mut state, true, "Voting is not enabled");
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .set_governance_token(GOVERNANCE_TOKEN)
+        .returns(ExpectError(4, "Only owner can configure voting"))
+        .run();
+    set_governance_token(&mut state);
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .set_governance_token(TestTokenIdentifier::new("OTHER-123456"))
+        .returns(ExpectError(4, "Governance token already set"))
+        .run();
+
+    // No period configured yet, so the window closes at the audit timestamp
+    vote_expect_error(&mut state, true, "Voting window closed");
+
+    state.run_admin_action(proxy::AdminAction::SetVotingPeriod(VOTING_PERIOD));
+    state.register_audit(AUDITOR, 2, 4, "audit");
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .vote_on_audit(contract_hash(2), 1usize, true)
+        .egld(10u64)
+        .returns(ExpectError(4, "incorrect number of ESDT transfers"))
+        .run();
+    vote(&mut state, USER, 2, true, 10);
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .vote_on_audit(contract_hash(2), 1usize, false)
+        .payment(TestEsdtTransfer(GOVERNANCE_TOKEN, 0, 10u64))
+        .returns(ExpectError(4, "Cannot change vote direction"))
+        .run();
+}
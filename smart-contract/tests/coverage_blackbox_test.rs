@@ -0,0 +1,417 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const YEAR: u64 = 31_536_000;
+const CLAIM_VOTING_PERIOD: u64 = 1_209_600;
+const CAPITAL: u64 = 100_000;
+const COVERED: u64 = 10_000;
+// 10% a year of the covered amount, of which 10% goes to the treasury
+const PREMIUM: u64 = 1_000;
+const POOL_PREMIUM: u64 = 900;
+
+const UNDERWRITER: TestAddress = OTHER_AUDITOR;
+const HOLDER: TestAddress = PROJECT_OWNER;
+
+// Terms with a two-arbiter quorum, an underwriter deposit and a 5-star audit of contract 1
+fn setup() -> RegistryTestState {
+    let mut state = RegistryTestState::new();
+    state.run_admin_action(proxy::AdminAction::SetCoverageTerms(proxy::CoverageTerms {
+        min_stars: 4,
+        premium_bps: 1_000,
+        protocol_fee_bps: 1_000,
+        claim_quorum: 2,
+    }));
+    state.run_admin_action(proxy::AdminAction::AddArbiter(USER.to_managed_address()));
+    state.run_admin_action(proxy::AdminAction::AddArbiter(THIRD_AUDITOR.to_managed_address()));
+    state.register_audit(AUDITOR, 1, 5, "audit");
+    deposit(&mut state, UNDERWRITER, CAPITAL);
+    state
+}
+
+fn deposit(state: &mut RegistryTestState, underwriter: TestAddress, amount: u64) {
+    state
+        .world
+        .tx()
+        .from(underwriter)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .deposit_coverage_capital()
+        .egld(amount)
+        .run();
+}
+
+fn withdraw(state: &mut RegistryTestState, shares: u64) {
+    state
+        .world
+        .tx()
+        .from(UNDERWRITER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .withdraw_coverage_capital(BigUint::from(shares))
+        .run();
+}
+
+fn buy_coverage(state: &mut RegistryTestState) -> usize {
+    state
+        .world
+        .tx()
+        .from(HOLDER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .buy_coverage(contract_hash(1), BigUint::from(COVERED), YEAR)
+        .egld(PREMIUM)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn buy_expect_error(
+    state: &mut RegistryTestState,
+    hash_byte: u8,
+    covered: u64,
+    period: u64,
+    premium: u64,
+    message: &str,
+) {
+    state
+        .world
+        .tx()
+        .from(HOLDER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .buy_coverage(contract_hash(hash_byte), BigUint::from(covered), period)
+        .egld(premium)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn file_claim(state: &mut RegistryTestState, policy_id: usize) {
+    let exploited_at = state.timestamp;
+    state
+        .world
+        .tx()
+        .from(HOLDER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .file_coverage_claim(policy_id, contract_hash(9), exploited_at)
+        .run();
+}
+
+fn vote(state: &mut RegistryTestState, arbiter: TestAddress, policy_id: usize, approve: bool) {
+    state
+        .world
+        .tx()
+        .from(arbiter)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .vote_on_claim(policy_id, approve)
+        .run();
+}
+
+fn policy_status(state: &mut RegistryTestState, policy_id: usize) -> proxy::PolicyStatus {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_coverage_policy(policy_id)
+        .returns(ReturnsResult)
+        .run()
+        .status
+}
+
+// Capital, shares and active coverage
+fn pool(state: &mut RegistryTestState) -> (u64, u64, u64) {
+    let (capital, shares, active) = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_coverage_pool()
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    (capital.to_u64().unwrap(), shares.to_u64().unwrap(), active.to_u64().unwrap())
+}
+
+fn underwriter_position(state: &mut RegistryTestState, underwriter: TestAddress) -> (u64, u64) {
+    let (shares, value) = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_underwriter_position(underwriter.to_managed_address())
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    (shares.to_u64().unwrap(), value.to_u64().unwrap())
+}
+
+fn claim_expect_error(state: &mut RegistryTestState, from: TestAddress, policy_id: usize, message: &str) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .file_coverage_claim(policy_id, contract_hash(9), START_TIMESTAMP)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn approve_expect_error(state: &mut RegistryTestState, arbiter: TestAddress, message: &str) {
+    state
+        .world
+        .tx()
+        .from(arbiter)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .vote_on_claim(1usize, true)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn release_expect_error(state: &mut RegistryTestState, message: &str) {
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .release_coverage(1usize)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn expire_expect_error(state: &mut RegistryTestState, message: &str) {
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .expire_coverage_claim(1usize)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+#[test]
+fn premiums_grow_the_pool() {
+    let mut state = setup();
+    let quote = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .quote_premium(BigUint::from(COVERED), YEAR)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(quote, BigUint::from(PREMIUM));
+
+    assert_eq!(buy_coverage(&mut state), 1);
+    assert_eq!(pool(&mut state), (CAPITAL + POOL_PREMIUM, CAPITAL, COVERED));
+    assert_eq!(underwriter_position(&mut state, UNDERWRITER), (CAPITAL, CAPITAL + POOL_PREMIUM));
+    assert_eq!(policy_status(&mut state, 1), proxy::PolicyStatus::Active);
+
+    // The protocol fee is left unlocked for the treasury
+    state.world.check_account(REGISTRY).balance(CAPITAL + PREMIUM);
+    let locked = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .locked_funds(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(locked, BigUint::from(CAPITAL + POOL_PREMIUM));
+
+    let policies = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contract_policies(contract_hash(1))
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(policies, vec![1]);
+}
+
+#[test]
+fn buy_coverage_rejections() {
+    let mut state = RegistryTestState::new();
+    buy_expect_error(&mut state, 1, COVERED, YEAR, PREMIUM, "Coverage is not enabled");
+
+    let mut state = setup();
+    state.register_audit(AUDITOR, 2, 3, "weak audit");
+    buy_expect_error(&mut state, 1, 0, YEAR, PREMIUM, "Covered amount cannot be zero");
+    buy_expect_error(&mut state, 1, COVERED, 3_600, PREMIUM, "Invalid coverage period");
+    buy_expect_error(&mut state, 1, COVERED, YEAR + 1, PREMIUM, "Invalid coverage period");
+    buy_expect_error(&mut state, 2, COVERED, YEAR, PREMIUM, "Contract audit does not qualify for coverage");
+    buy_expect_error(&mut state, 3, COVERED, YEAR, PREMIUM, "Contract audit does not qualify for coverage");
+    buy_expect_error(&mut state, 1, CAPITAL + 1, YEAR, PREMIUM, "Insufficient coverage capital");
+    buy_expect_error(&mut state, 1, 1, 86_400, 0, "Covered amount too small");
+    buy_expect_error(&mut state, 1, COVERED, YEAR, PREMIUM - 1, "Premium must match the quoted amount");
+}
+
+#[test]
+fn underwriters_share_premiums_and_keep_backing_capital() {
+    let mut state = setup();
+    buy_coverage(&mut state);
+
+    state
+        .world
+        .tx()
+        .from(UNDERWRITER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .deposit_coverage_capital()
+        .egld(0u64)
+        .returns(ExpectError(4, "Deposit cannot be zero"))
+        .run();
+    for (shares, message) in [
+        (0, "Invalid share amount"),
+        (CAPITAL + 1, "Invalid share amount"),
+        (CAPITAL, "Capital backs active coverage"),
+    ] {
+        state
+            .world
+            .tx()
+            .from(UNDERWRITER)
+            .to(REGISTRY)
+            .typed(proxy::AuditRegistryProxy)
+            .withdraw_coverage_capital(BigUint::from(shares))
+            .returns(ExpectError(4, message))
+            .run();
+    }
+
+    // 90% of the shares are worth 90,810 of the 100,900 pool, leaving 10,090 behind the policy
+    withdraw(&mut state, 90_000);
+    state
+        .world
+        .check_account(UNDERWRITER)
+        .balance(INITIAL_BALANCE - CAPITAL + 90_810);
+
+    // Later deposits buy shares at the grown share price
+    deposit(&mut state, THIRD_AUDITOR, 1_009);
+    assert_eq!(underwriter_position(&mut state, THIRD_AUDITOR), (1_000, 1_009));
+
+    state.advance_time(YEAR + 1);
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .release_coverage(1usize)
+        .run();
+    withdraw(&mut state, CAPITAL - 90_000);
+    assert_eq!(pool(&mut state), (1_009, 1_000, 0));
+    state
+        .world
+        .check_account(UNDERWRITER)
+        .balance(INITIAL_BALANCE + POOL_PREMIUM);
+}
+
+#[test]
+fn approved_claims_pay_out() {
+    let mut state = setup();
+    buy_coverage(&mut state);
+    state.advance_time(100);
+
+    claim_expect_error(&mut state, USER, 1, "Only the policy holder can claim");
+    claim_expect_error(&mut state, HOLDER, 2, "Policy not found");
+    claim_expect_error(&mut state, HOLDER, 1, "Exploit outside the coverage period");
+    approve_expect_error(&mut state, USER, "No pending claim");
+
+    file_claim(&mut state, 1);
+    assert_eq!(policy_status(&mut state, 1), proxy::PolicyStatus::ClaimPending);
+    claim_expect_error(&mut state, HOLDER, 1, "Policy is not active");
+    approve_expect_error(&mut state, AUDITOR, "Only arbiters can vote on claims");
+
+    vote(&mut state, USER, 1, true);
+    approve_expect_error(&mut state, USER, "Already voted");
+    vote(&mut state, THIRD_AUDITOR, 1, true);
+
+    assert_eq!(policy_status(&mut state, 1), proxy::PolicyStatus::PaidOut);
+    assert_eq!(pool(&mut state), (CAPITAL + POOL_PREMIUM - COVERED, CAPITAL, 0));
+    state
+        .world
+        .check_account(HOLDER)
+        .balance(INITIAL_BALANCE - PREMIUM + COVERED);
+    approve_expect_error(&mut state, USER, "No pending claim");
+}
+
+#[test]
+fn rejected_claims_reactivate_the_policy_until_it_is_released() {
+    let mut state = setup();
+    buy_coverage(&mut state);
+    state.advance_time(100);
+    file_claim(&mut state, 1);
+    release_expect_error(&mut state, "Policy is not active");
+
+    vote(&mut state, USER, 1, false);
+    vote(&mut state, THIRD_AUDITOR, 1, false);
+    assert_eq!(policy_status(&mut state, 1), proxy::PolicyStatus::Active);
+    assert_eq!(pool(&mut state), (CAPITAL + POOL_PREMIUM, CAPITAL, COVERED));
+
+    release_expect_error(&mut state, "Coverage period has not ended");
+    state.advance_time(YEAR);
+    claim_expect_error(&mut state, HOLDER, 1, "Coverage period has ended");
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .release_coverage(1usize)
+        .run();
+    assert_eq!(policy_status(&mut state, 1), proxy::PolicyStatus::Released);
+    assert_eq!(pool(&mut state), (CAPITAL + POOL_PREMIUM, CAPITAL, 0));
+}
+
+#[test]
+fn claims_without_quorum_expire() {
+    let mut state = setup();
+    buy_coverage(&mut state);
+    state.advance_time(100);
+    file_claim(&mut state, 1);
+    vote(&mut state, USER, 1, true);
+
+    expire_expect_error(&mut state, "Claim voting still open");
+    state.advance_time(CLAIM_VOTING_PERIOD + 1);
+    approve_expect_error(&mut state, THIRD_AUDITOR, "Claim voting has ended");
+
+    // Within the coverage period the policy simply becomes active again
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .expire_coverage_claim(1usize)
+        .run();
+    assert_eq!(policy_status(&mut state, 1), proxy::PolicyStatus::Active);
+    expire_expect_error(&mut state, "No pending claim");
+
+    // Once it has ended, the expiry also frees the capital backing it
+    file_claim(&mut state, 1);
+    state.advance_time(YEAR);
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .expire_coverage_claim(1usize)
+        .run();
+    assert_eq!(policy_status(&mut state, 1), proxy::PolicyStatus::Released);
+    assert_eq!(pool(&mut state), (CAPITAL + POOL_PREMIUM, CAPITAL, 0));
+
+    withdraw(&mut state, CAPITAL);
+    state
+        .world
+        .check_account(UNDERWRITER)
+        .balance(INITIAL_BALANCE + POOL_PREMIUM);
+}
@@ -0,0 +1,285 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const PROPOSAL_VOTING_PERIOD: u64 = 259_200;
+const PROPOSAL_TIMELOCK: u64 = 172_800;
+
+fn propose(state: &mut RegistryTestState, action: proxy::ProposalAction<StaticApi>) -> usize {
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .propose(action, ManagedBuffer::from("proposal"))
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn vote(state: &mut RegistryTestState, voter: TestAddress, proposal_id: usize, approve: bool) {
+    state
+        .world
+        .tx()
+        .from(voter)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .vote_proposal(proposal_id, approve)
+        .run();
+}
+
+fn status(state: &mut RegistryTestState, proposal_id: usize) -> proxy::ProposalStatus {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_proposal_status(proposal_id)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn execute(state: &mut RegistryTestState, proposal_id: usize) {
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .execute_proposal(proposal_id)
+        .run();
+}
+
+fn execute_expect_error(state: &mut RegistryTestState, proposal_id: usize) {
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .execute_proposal(proposal_id)
+        .returns(ExpectError(4, "Proposal is not executable"))
+        .run();
+}
+
+fn max_summary_length(state: &mut RegistryTestState) -> usize {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_max_summary_length()
+        .returns(ReturnsResult)
+        .run()
+}
+
+// The three test auditors are admitted through the owner's timelock
+fn setup() -> RegistryTestState {
+    let mut state = RegistryTestState::new();
+    for auditor in [AUDITOR, OTHER_AUDITOR, THIRD_AUDITOR] {
+        state.run_admin_action(proxy::AdminAction::AdmitAuditor(auditor.to_managed_address()));
+    }
+    state
+}
+
+#[test]
+fn proposal_passes_after_quorum_and_timelock() {
+    let mut state = setup();
+    let proposal_id = propose(&mut state, proxy::ProposalAction::SetMaxSummaryLength(1_000));
+    for auditor in [AUDITOR, OTHER_AUDITOR, THIRD_AUDITOR] {
+        vote(&mut state, auditor, proposal_id, true);
+    }
+    assert_eq!(status(&mut state, proposal_id), proxy::ProposalStatus::Active);
+    execute_expect_error(&mut state, proposal_id);
+
+    state.advance_time(PROPOSAL_VOTING_PERIOD);
+    assert_eq!(status(&mut state, proposal_id), proxy::ProposalStatus::Queued);
+    execute_expect_error(&mut state, proposal_id);
+
+    state.advance_time(PROPOSAL_TIMELOCK);
+    assert_eq!(status(&mut state, proposal_id), proxy::ProposalStatus::Executable);
+    execute(&mut state, proposal_id);
+    assert_eq!(status(&mut state, proposal_id), proxy::ProposalStatus::Executed);
+    assert_eq!(max_summary_length(&mut state), 1_000);
+    execute_expect_error(&mut state, proposal_id);
+}
+
+#[test]
+fn registration_fee_is_governed() {
+    let mut state = setup();
+    let proposal_id = propose(
+        &mut state,
+        proxy::ProposalAction::SetRegistrationFee(BigUint::from(250u64)),
+    );
+    for auditor in [AUDITOR, OTHER_AUDITOR, THIRD_AUDITOR] {
+        vote(&mut state, auditor, proposal_id, true);
+    }
+    state.advance_time(PROPOSAL_VOTING_PERIOD + PROPOSAL_TIMELOCK);
+    execute(&mut state, proposal_id);
+
+    let fee = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .registration_fee()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(fee, BigUint::from(250u64));
+}
+
+#[test]
+fn proposal_is_defeated_without_quorum_or_majority() {
+    let mut state = setup();
+    let below_quorum = propose(&mut state, proxy::ProposalAction::SetMaxSummaryLength(500));
+    vote(&mut state, AUDITOR, below_quorum, true);
+    vote(&mut state, OTHER_AUDITOR, below_quorum, true);
+
+    let rejected = propose(&mut state, proxy::ProposalAction::SetMaxSummaryLength(600));
+    vote(&mut state, AUDITOR, rejected, true);
+    vote(&mut state, OTHER_AUDITOR, rejected, false);
+    vote(&mut state, THIRD_AUDITOR, rejected, false);
+
+    state.advance_time(PROPOSAL_VOTING_PERIOD + PROPOSAL_TIMELOCK);
+    for proposal_id in [below_quorum, rejected] {
+        assert_eq!(status(&mut state, proposal_id), proxy::ProposalStatus::Defeated);
+        execute_expect_error(&mut state, proposal_id);
+    }
+}
+
+#[test]
+fn registered_but_unadmitted_auditors_cannot_govern() {
+    let mut state = RegistryTestState::new();
+    // An audit history alone is cheap to obtain and grants no votes
+    for (hash_byte, auditor) in [(1u8, AUDITOR), (2, OTHER_AUDITOR), (3, THIRD_AUDITOR)] {
+        state.register_audit(auditor, hash_byte, 3, "throwaway");
+    }
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .propose(
+            proxy::ProposalAction::SetAdmissionRequired(true),
+            ManagedBuffer::from("proposal"),
+        )
+        .returns(ExpectError(4, "Only admitted auditors can govern"))
+        .run();
+
+    state.run_admin_action(proxy::AdminAction::AdmitAuditor(AUDITOR.to_managed_address()));
+    let proposal_id = propose(&mut state, proxy::ProposalAction::SetMaxSummaryLength(500));
+    state
+        .world
+        .tx()
+        .from(OTHER_AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .vote_proposal(proposal_id, true)
+        .returns(ExpectError(4, "Only admitted auditors can govern"))
+        .run();
+}
+
+#[test]
+fn owner_can_veto_until_execution() {
+    let mut state = setup();
+    let proposal_id = propose(&mut state, proxy::ProposalAction::SetMaxSummaryLength(777));
+    for auditor in [AUDITOR, OTHER_AUDITOR, THIRD_AUDITOR] {
+        vote(&mut state, auditor, proposal_id, true);
+    }
+    state.advance_time(PROPOSAL_VOTING_PERIOD);
+
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .veto_proposal(proposal_id)
+        .returns(ExpectError(4, "Only owner can veto proposals"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .veto_proposal(proposal_id)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .veto_proposal(proposal_id)
+        .returns(ExpectError(4, "Proposal already vetoed"))
+        .run();
+
+    state.advance_time(PROPOSAL_TIMELOCK);
+    assert_eq!(status(&mut state, proposal_id), proxy::ProposalStatus::Vetoed);
+    execute_expect_error(&mut state, proposal_id);
+    assert_ne!(max_summary_length(&mut state), 777);
+}
+
+#[test]
+fn governance_rejections() {
+    let mut state = setup();
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .propose(
+            proxy::ProposalAction::SetMaxSummaryLength(4_097),
+            ManagedBuffer::from("proposal"),
+        )
+        .returns(ExpectError(4, "Summary limit out of range"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .propose(
+            proxy::ProposalAction::SetAuditValidityPeriod(315_360_001),
+            ManagedBuffer::from("proposal"),
+        )
+        .returns(ExpectError(4, "Validity period out of range"))
+        .run();
+
+    let proposal_id = propose(&mut state, proxy::ProposalAction::SetMaxSummaryLength(500));
+    vote(&mut state, AUDITOR, proposal_id, true);
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .vote_proposal(proposal_id, false)
+        .returns(ExpectError(4, "Already voted"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .vote_proposal(proposal_id + 1, true)
+        .returns(ExpectError(4, "Proposal not found"))
+        .run();
+
+    state.advance_time(PROPOSAL_VOTING_PERIOD);
+    state
+        .world
+        .tx()
+        .from(OTHER_AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .vote_proposal(proposal_id, true)
+        .returns(ExpectError(4, "Voting has ended"))
+        .run();
+}
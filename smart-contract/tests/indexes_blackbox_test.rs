@@ -0,0 +1,271 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+fn contracts_by_min_stars(state: &mut RegistryTestState, min_stars: u8) -> Vec<(ManagedByteArray<StaticApi, 32>, u8)> {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contracts_by_min_stars(min_stars, 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|item| item.into_tuple())
+        .collect()
+}
+
+fn add_moderator(state: &mut RegistryTestState) {
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .add_moderator(USER.to_managed_address())
+        .run();
+}
+
+#[test]
+fn star_index_follows_the_latest_audit() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 2, "first");
+    state.register_audit(AUDITOR, 2, 4, "other contract");
+    assert_eq!(
+        contracts_by_min_stars(&mut state, 0),
+        vec![(contract_hash(2), 4), (contract_hash(1), 2)]
+    );
+
+    state.register_audit(OTHER_AUDITOR, 1, 5, "re-audit");
+    assert_eq!(
+        contracts_by_min_stars(&mut state, 3),
+        vec![(contract_hash(1), 5), (contract_hash(2), 4)]
+    );
+
+    state
+        .world
+        .tx()
+        .from(OTHER_AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .amend_audit(contract_hash(1), 2usize, 1u8, ManagedBuffer::from("downgraded"))
+        .run();
+    assert_eq!(contracts_by_min_stars(&mut state, 3), vec![(contract_hash(2), 4)]);
+    assert_eq!(contracts_by_min_stars(&mut state, 1).last(), Some(&(contract_hash(1), 1)));
+
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contracts_by_min_stars(6u8, 0usize, 10usize)
+        .returns(ExpectError(4, "Stars must be between 0 and 5"))
+        .run();
+}
+
+#[test]
+fn revoked_audits_fall_back_to_the_previous_rating() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 2, "first");
+    state.register_audit(OTHER_AUDITOR, 1, 5, "second");
+
+    state
+        .world
+        .tx()
+        .from(OTHER_AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .revoke_audit(contract_hash(1), 2usize)
+        .run();
+    assert_eq!(contracts_by_min_stars(&mut state, 0), vec![(contract_hash(1), 2)]);
+
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .revoke_audit(contract_hash(1), 1usize)
+        .run();
+    assert_eq!(contracts_by_min_stars(&mut state, 0), vec![]);
+}
+
+#[test]
+fn hidden_audits_and_contracts_leave_the_star_index() {
+    let mut state = RegistryTestState::new();
+    add_moderator(&mut state);
+    state.register_audit(AUDITOR, 1, 2, "first");
+    state.register_audit(OTHER_AUDITOR, 1, 5, "second");
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_audit(contract_hash(1), 2usize, proxy::ModerationReason::Misleading)
+        .run();
+    assert_eq!(contracts_by_min_stars(&mut state, 0), vec![(contract_hash(1), 2)]);
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_contract(contract_hash(1), proxy::ModerationReason::Spam)
+        .run();
+    assert_eq!(contracts_by_min_stars(&mut state, 0), vec![]);
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unhide_contract(contract_hash(1), proxy::ModerationReason::Spam)
+        .run();
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unhide_audit(contract_hash(1), 2usize, proxy::ModerationReason::Misleading)
+        .run();
+    assert_eq!(contracts_by_min_stars(&mut state, 0), vec![(contract_hash(1), 5)]);
+}
+
+fn audits_between(
+    state: &mut RegistryTestState,
+    from_timestamp: u64,
+    to_timestamp: u64,
+    start_index: usize,
+) -> Vec<(u8, usize)> {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_audits_between(from_timestamp, to_timestamp, start_index, 10usize)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+        .into_iter()
+        .map(|item| {
+            let (contract_hash, audit_index, _) = item.into_tuple();
+            (contract_hash[0], audit_index)
+        })
+        .collect()
+}
+
+fn recent_audits(state: &mut RegistryTestState, start_index: usize, limit: usize) -> Vec<(u8, usize)> {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_recent_audits(start_index, limit)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+        .into_iter()
+        .map(|item| {
+            let (contract_hash, audit_index, _) = item.into_tuple();
+            (contract_hash[0], audit_index)
+        })
+        .collect()
+}
+
+// Audits 1/1, 2/1, 1/2 and 3/1 a day apart; 1/2 is revoked and contract 3 is hidden
+fn register_feed(state: &mut RegistryTestState) {
+    add_moderator(state);
+    state.register_audit(AUDITOR, 1, 4, "first");
+    state.advance_time(86_400);
+    state.register_audit(AUDITOR, 2, 3, "second");
+    state.advance_time(86_400);
+    state.register_audit(OTHER_AUDITOR, 1, 5, "revoked");
+    state.advance_time(86_400);
+    state.register_audit(AUDITOR, 3, 2, "hidden");
+
+    state
+        .world
+        .tx()
+        .from(OTHER_AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .revoke_audit(contract_hash(1), 2usize)
+        .run();
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_contract(contract_hash(3), proxy::ModerationReason::Spam)
+        .run();
+}
+
+#[test]
+fn time_range_view_skips_hidden_and_revoked_audits() {
+    let mut state = RegistryTestState::new();
+    register_feed(&mut state);
+    let now = state.timestamp;
+
+    assert_eq!(audits_between(&mut state, 0, now, 0), vec![(1, 1), (2, 1)]);
+    assert_eq!(audits_between(&mut state, 0, now, 1), vec![(2, 1)]);
+    assert_eq!(audits_between(&mut state, START_TIMESTAMP + 1, now, 0), vec![(2, 1)]);
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unhide_contract(contract_hash(3), proxy::ModerationReason::Spam)
+        .run();
+    assert_eq!(audits_between(&mut state, 0, now, 0), vec![(1, 1), (2, 1), (3, 1)]);
+}
+
+#[test]
+fn time_range_view_is_validated() {
+    let mut state = RegistryTestState::new();
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_audits_between(2_000u64, 1_000u64, 0usize, 10usize)
+        .returns(ExpectError(4, "Invalid time range"))
+        .run();
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_audits_between(0u64, 366 * 86_400u64, 0usize, 10usize)
+        .returns(ExpectError(4, "Time range too large"))
+        .run();
+}
+
+#[test]
+fn recent_audits_skip_hidden_and_revoked_audits() {
+    let mut state = RegistryTestState::new();
+    register_feed(&mut state);
+
+    assert_eq!(recent_audits(&mut state, 0, 10), vec![(2, 1), (1, 1)]);
+    assert_eq!(recent_audits(&mut state, 0, 1), vec![(2, 1)]);
+    assert_eq!(recent_audits(&mut state, 1, 10), vec![(1, 1)]);
+    assert_eq!(recent_audits(&mut state, 2, 10), vec![]);
+
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_recent_audits(4usize, 10usize)
+        .returns(ExpectError(4, "Start index out of bounds"))
+        .run();
+}
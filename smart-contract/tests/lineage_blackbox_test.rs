@@ -0,0 +1,147 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+fn record_upgrade(state: &mut RegistryTestState, from: TestAddress, hash_byte: u8) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .record_upgrade(PROJECT_SC, contract_hash(hash_byte))
+        .run();
+}
+
+fn record_upgrade_expect_error(
+    state: &mut RegistryTestState,
+    from: TestAddress,
+    sc_address: ManagedAddress<StaticApi>,
+    hash_byte: u8,
+    message: &str,
+) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .record_upgrade(sc_address, contract_hash(hash_byte))
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+// Code hash and audit index of every audit in PROJECT_SC's lineage
+fn lineage_audits(state: &mut RegistryTestState, visible_only: bool) -> Vec<(u8, usize)> {
+    let query = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy);
+    let audits = if visible_only {
+        query
+            .get_visible_lineage_audits(PROJECT_SC)
+            .returns(ReturnsResult)
+            .run()
+    } else {
+        query
+            .get_lineage_audits(PROJECT_SC)
+            .returns(ReturnsResult)
+            .run()
+    };
+    audits
+        .into_iter()
+        .map(|entry| {
+            let (hash, audit_index, _) = entry.into_tuple();
+            (hash.to_byte_array()[0], audit_index)
+        })
+        .collect()
+}
+
+#[test]
+fn upgrades_extend_the_lineage() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    record_upgrade(&mut state, PROJECT_OWNER, 2);
+    state.register_audit(OTHER_AUDITOR, 2, 5, "audit of v2");
+
+    let versions = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contract_lineage(PROJECT_SC)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[1].recorded_at, START_TIMESTAMP);
+
+    // The new version inherits the project link, so the owner can record the next upgrade
+    let link = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_project_link(contract_hash(2))
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert_eq!(link.project_owner, PROJECT_OWNER.to_managed_address());
+
+    // Rolling back to v1 lists its audits only once
+    record_upgrade(&mut state, PROJECT_OWNER, 1);
+    assert_eq!(lineage_audits(&mut state, false), vec![(1, 1), (2, 1)]);
+}
+
+#[test]
+fn visible_lineage_skips_moderated_audits() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    state.register_audit(OTHER_AUDITOR, 1, 1, "spam");
+    record_upgrade(&mut state, OWNER, 2);
+    state.register_audit(OTHER_AUDITOR, 2, 5, "audit of v2");
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_audit(contract_hash(1), 2usize, proxy::ModerationReason::Spam)
+        .run();
+    assert_eq!(
+        lineage_audits(&mut state, false),
+        vec![(1, 1), (1, 2), (2, 1)]
+    );
+    assert_eq!(lineage_audits(&mut state, true), vec![(1, 1), (2, 1)]);
+}
+
+#[test]
+fn record_upgrade_rejections() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    record_upgrade_expect_error(
+        &mut state,
+        PROJECT_OWNER,
+        USER.to_managed_address(),
+        2,
+        "Contract address not linked",
+    );
+    record_upgrade_expect_error(
+        &mut state,
+        PROJECT_OWNER,
+        PROJECT_SC.to_managed_address(),
+        1,
+        "Code hash unchanged",
+    );
+    record_upgrade_expect_error(
+        &mut state,
+        AUDITOR,
+        PROJECT_SC.to_managed_address(),
+        2,
+        "Only the project owner can record upgrades",
+    );
+}
@@ -0,0 +1,287 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+fn visible_audits(state: &mut RegistryTestState) -> Vec<ManagedByteArray<StaticApi, 32>> {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_visible_audits(0usize, 10usize, OptionalValue::<proxy::AuditKind>::None)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|item| item.into_tuple().0)
+        .collect()
+}
+
+fn add_moderator(state: &mut RegistryTestState, moderator: TestAddress) {
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .add_moderator(moderator.to_managed_address())
+        .run();
+}
+
+#[test]
+fn only_the_owner_manages_moderators() {
+    let mut state = RegistryTestState::new();
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .add_moderator(USER.to_managed_address())
+        .returns(ExpectError(4, "Only owner can manage moderators"))
+        .run();
+
+    state.register_audit(AUDITOR, 1, 4, "audit");
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_contract(contract_hash(1), proxy::ModerationReason::Spam)
+        .returns(ExpectError(4, "Only moderators can moderate"))
+        .run();
+
+    add_moderator(&mut state, USER);
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_contract(contract_hash(1), proxy::ModerationReason::Spam)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .remove_moderator(USER.to_managed_address())
+        .returns(ExpectError(4, "Only owner can manage moderators"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .remove_moderator(USER.to_managed_address())
+        .run();
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unhide_contract(contract_hash(1), proxy::ModerationReason::Spam)
+        .returns(ExpectError(4, "Only moderators can moderate"))
+        .run();
+}
+
+#[test]
+fn hidden_audits_fall_back_to_the_latest_visible_one() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "original");
+    state.register_audit(OTHER_AUDITOR, 1, 1, "abusive");
+    add_moderator(&mut state, USER);
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_audit(contract_hash(1), 2usize, proxy::ModerationReason::Abusive)
+        .run();
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_audit(contract_hash(1), 2usize, proxy::ModerationReason::Abusive)
+        .returns(ExpectError(4, "Audit already hidden"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_audit(contract_hash(1), 3usize, proxy::ModerationReason::Abusive)
+        .returns(ExpectError(4, "Audit not found"))
+        .run();
+
+    let latest_visible = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_latest_visible_audit(contract_hash(1))
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(latest_visible.summary, ManagedBuffer::from("original"));
+    // The unfiltered view still returns the hidden audit
+    assert_eq!(state.latest_audit(1).summary, ManagedBuffer::from("abusive"));
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unhide_audit(contract_hash(1), 2usize, proxy::ModerationReason::AppealAccepted)
+        .run();
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unhide_audit(contract_hash(1), 2usize, proxy::ModerationReason::AppealAccepted)
+        .returns(ExpectError(4, "Audit is not hidden"))
+        .run();
+}
+
+#[test]
+fn hidden_contracts_leave_the_visible_views() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "audit");
+    state.register_audit(AUDITOR, 2, 4, "spam");
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_contract(contract_hash(3), proxy::ModerationReason::Spam)
+        .returns(ExpectError(4, "No audits found for this contract"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_contract(contract_hash(2), proxy::ModerationReason::Spam)
+        .run();
+
+    assert_eq!(visible_audits(&mut state), vec![contract_hash(1)]);
+    let visible_history: Vec<_> = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_visible_auditor_history(AUDITOR.to_managed_address())
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(visible_history, vec![contract_hash(1)]);
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unhide_contract(contract_hash(2), proxy::ModerationReason::AppealAccepted)
+        .run();
+    assert_eq!(visible_audits(&mut state), vec![contract_hash(1), contract_hash(2)]);
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unhide_contract(contract_hash(2), proxy::ModerationReason::AppealAccepted)
+        .returns(ExpectError(4, "Contract is not hidden"))
+        .run();
+}
+
+#[test]
+fn blocked_auditors_and_the_moderation_log() {
+    let mut state = RegistryTestState::new();
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_moderation_log(0usize, 10usize)
+        .returns(ExpectError(4, "Start index out of bounds"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .block_auditor(AUDITOR.to_managed_address(), proxy::ModerationReason::Spam)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .block_auditor(AUDITOR.to_managed_address(), proxy::ModerationReason::Spam)
+        .returns(ExpectError(4, "Auditor already blocked"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audit(contract_hash(1), 4u8, ManagedBuffer::from("audit"))
+        .returns(ExpectError(4, "Auditor is blocked"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unblock_auditor(AUDITOR.to_managed_address(), proxy::ModerationReason::AppealAccepted)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unblock_auditor(AUDITOR.to_managed_address(), proxy::ModerationReason::AppealAccepted)
+        .returns(ExpectError(4, "Auditor is not blocked"))
+        .run();
+    state.register_audit(AUDITOR, 1, 4, "audit");
+
+    let log: Vec<_> = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_moderation_log(0usize, 10usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(log.len(), 2);
+    assert_eq!(log[0].action, proxy::ModerationActionKind::BlockAuditor);
+    assert_eq!(log[1].action, proxy::ModerationActionKind::UnblockAuditor);
+    assert_eq!(log[1].reason, proxy::ModerationReason::AppealAccepted);
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+fn review(state: &mut RegistryTestState, reviewer: TestAddress, verdict: proxy::ReviewVerdict) {
+    state
+        .world
+        .tx()
+        .from(reviewer)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .review_audit(contract_hash(1), 1usize, verdict, ManagedBuffer::from("checked"))
+        .run();
+}
+
+fn review_expect_error(
+    state: &mut RegistryTestState,
+    reviewer: TestAddress,
+    audit_index: usize,
+    comment: &[u8],
+    message: &str,
+) {
+    state
+        .world
+        .tx()
+        .from(reviewer)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .review_audit(
+            contract_hash(1),
+            audit_index,
+            proxy::ReviewVerdict::Endorse,
+            ManagedBuffer::from(comment),
+        )
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn reputation(state: &mut RegistryTestState, auditor: TestAddress) -> proxy::AuditorReputation {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_auditor_reputation(auditor.to_managed_address())
+        .returns(ReturnsResult)
+        .run()
+}
+
+// AUDITOR audits contract 1; the other two auditors get history of their own
+fn setup() -> RegistryTestState {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "audit");
+    state.register_audit(OTHER_AUDITOR, 2, 4, "audit");
+    state.register_audit(THIRD_AUDITOR, 3, 4, "audit");
+    state
+}
+
+#[test]
+fn reviews_update_the_audit_and_reputation() {
+    let mut state = setup();
+    review(&mut state, OTHER_AUDITOR, proxy::ReviewVerdict::Endorse);
+    review(&mut state, THIRD_AUDITOR, proxy::ReviewVerdict::Contest);
+
+    let audit = state.latest_audit(1);
+    assert_eq!(audit.endorsements, 1);
+    assert_eq!(audit.contests, 1);
+
+    let reviews = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_audit_reviews(contract_hash(1), 1usize)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(reviews.len(), 2);
+    assert_eq!(reviews[0].reviewer, OTHER_AUDITOR.to_managed_address());
+    assert_eq!(reviews[1].verdict, proxy::ReviewVerdict::Contest);
+
+    let rep = reputation(&mut state, AUDITOR);
+    assert_eq!(rep.audited_contracts, 1);
+    assert_eq!(rep.endorsements_received, 1);
+    assert_eq!(rep.contests_received, 1);
+    // 1 + 1 * 2 - 1 * 2
+    assert_eq!(rep.score, 1);
+}
+
+#[test]
+fn reputation_score_saturates_at_zero() {
+    let mut state = setup();
+    review(&mut state, OTHER_AUDITOR, proxy::ReviewVerdict::Contest);
+    review(&mut state, THIRD_AUDITOR, proxy::ReviewVerdict::Contest);
+    assert_eq!(reputation(&mut state, AUDITOR).score, 0);
+}
+
+#[test]
+fn review_rejections() {
+    let mut state = setup();
+    review_expect_error(&mut state, USER, 1, b"ok", "Only registered auditors can review");
+    review_expect_error(&mut state, AUDITOR, 1, b"ok", "Cannot review your own audit");
+    review_expect_error(&mut state, OTHER_AUDITOR, 2, b"ok", "Audit not found");
+    review_expect_error(&mut state, OTHER_AUDITOR, 1, &[b'a'; 141], "Comment too long");
+
+    review(&mut state, OTHER_AUDITOR, proxy::ReviewVerdict::Endorse);
+    review_expect_error(&mut state, OTHER_AUDITOR, 1, b"again", "Audit already reviewed");
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .add_moderator(OWNER.to_managed_address())
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .block_auditor(THIRD_AUDITOR.to_managed_address(), proxy::ModerationReason::Spam)
+        .run();
+    review_expect_error(&mut state, THIRD_AUDITOR, 1, b"ok", "Auditor is blocked");
+}
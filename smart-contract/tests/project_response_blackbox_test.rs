@@ -0,0 +1,312 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+fn respond(
+    state: &mut RegistryTestState,
+    status: proxy::ResponseStatus,
+    fixed_code_hash: Option<u8>,
+) {
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .respond_to_audit(
+            contract_hash(1),
+            1usize,
+            status,
+            ManagedBuffer::from("response"),
+            OptionalValue::from(fixed_code_hash.map(contract_hash)),
+        )
+        .run();
+}
+
+fn respond_expect_error(
+    state: &mut RegistryTestState,
+    from: TestAddress,
+    status: proxy::ResponseStatus,
+    comment: &[u8],
+    fixed_code_hash: Option<u8>,
+    message: &str,
+) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .respond_to_audit(
+            contract_hash(1),
+            1usize,
+            status,
+            ManagedBuffer::from(comment),
+            OptionalValue::from(fixed_code_hash.map(contract_hash)),
+        )
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn link_expect_error(
+    state: &mut RegistryTestState,
+    from: TestAddress,
+    hash_byte: u8,
+    sc_address: &ManagedAddress<StaticApi>,
+    message: &str,
+) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .link_project(
+            contract_hash(hash_byte),
+            sc_address,
+            PROJECT_OWNER.to_managed_address(),
+        )
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+#[test]
+fn project_owner_acknowledges_and_then_fixes() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    respond(&mut state, proxy::ResponseStatus::Acknowledged, None);
+    assert_eq!(
+        state.latest_audit(1).project_response,
+        proxy::ResponseStatus::Acknowledged
+    );
+
+    state.register_audit(OTHER_AUDITOR, 2, 5, "audit of the fix");
+    respond(&mut state, proxy::ResponseStatus::Fixed, Some(2));
+    assert_eq!(
+        state.latest_audit(1).project_response,
+        proxy::ResponseStatus::Fixed
+    );
+
+    let response = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_audit_response(contract_hash(1), 1usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert_eq!(response.responder, PROJECT_OWNER.to_managed_address());
+    assert_eq!(response.fixed_code_hash, Some(contract_hash(2)));
+
+    let remediation = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_remediation_audits(contract_hash(1), 1usize)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(remediation.len(), 1);
+    assert_eq!(remediation[0].auditor, OTHER_AUDITOR.to_managed_address());
+}
+
+#[test]
+fn visible_remediation_audits_skip_revoked_and_hidden_ones() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    state.register_audit(OTHER_AUDITOR, 2, 5, "audit of the fix");
+    state.register_audit(THIRD_AUDITOR, 2, 1, "revoked");
+    state.register_audit(AUDITOR, 2, 1, "hidden");
+    respond(&mut state, proxy::ResponseStatus::Fixed, Some(2));
+
+    state
+        .world
+        .tx()
+        .from(THIRD_AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .revoke_audit(contract_hash(2), 2usize)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_audit(contract_hash(2), 3usize, proxy::ModerationReason::Spam)
+        .run();
+
+    let remediation = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_remediation_audits(contract_hash(1), 1usize)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(remediation.len(), 3);
+
+    let visible = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_visible_remediation_audits(contract_hash(1), 1usize)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(visible.len(), 1);
+    assert_eq!(visible[0].auditor, OTHER_AUDITOR.to_managed_address());
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_contract(contract_hash(2), proxy::ModerationReason::Spam)
+        .run();
+    let visible = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_visible_remediation_audits(contract_hash(1), 1usize)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert!(visible.is_empty());
+}
+
+#[test]
+fn response_rejections() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "audit");
+    respond_expect_error(
+        &mut state,
+        PROJECT_OWNER,
+        proxy::ResponseStatus::Acknowledged,
+        b"ok",
+        None,
+        "Project not linked",
+    );
+
+    state.link_project(1);
+    respond_expect_error(
+        &mut state,
+        AUDITOR,
+        proxy::ResponseStatus::Acknowledged,
+        b"ok",
+        None,
+        "Only the project owner can respond",
+    );
+    respond_expect_error(
+        &mut state,
+        PROJECT_OWNER,
+        proxy::ResponseStatus::NoResponse,
+        b"ok",
+        None,
+        "Invalid response",
+    );
+    respond_expect_error(
+        &mut state,
+        PROJECT_OWNER,
+        proxy::ResponseStatus::Fixed,
+        b"ok",
+        None,
+        "Fix must use a new code hash",
+    );
+    respond_expect_error(
+        &mut state,
+        PROJECT_OWNER,
+        proxy::ResponseStatus::Fixed,
+        b"ok",
+        Some(1),
+        "Fix must use a new code hash",
+    );
+    respond_expect_error(
+        &mut state,
+        PROJECT_OWNER,
+        proxy::ResponseStatus::Disputed,
+        b"ok",
+        Some(2),
+        "Only fixes have a code hash",
+    );
+    respond_expect_error(
+        &mut state,
+        PROJECT_OWNER,
+        proxy::ResponseStatus::Disputed,
+        &[b'a'; 281],
+        None,
+        "Comment too long",
+    );
+}
+
+#[test]
+fn link_and_unlink_rules() {
+    let mut state = RegistryTestState::new();
+    state.world.account(PROJECT_SC).nonce(1).code(CODE_PATH);
+    link_expect_error(
+        &mut state,
+        OWNER,
+        1,
+        &PROJECT_SC.to_managed_address(),
+        "No audits found for this contract",
+    );
+
+    state.register_audit(AUDITOR, 1, 4, "audit");
+    link_expect_error(
+        &mut state,
+        USER,
+        1,
+        &PROJECT_SC.to_managed_address(),
+        "Only moderators can moderate",
+    );
+    link_expect_error(
+        &mut state,
+        OWNER,
+        1,
+        &USER.to_managed_address(),
+        "Address is not a smart contract",
+    );
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .link_project(contract_hash(1), PROJECT_SC, PROJECT_OWNER)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unlink_project(contract_hash(1))
+        .run();
+    let link = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_project_link(contract_hash(1))
+        .returns(ReturnsResult)
+        .run();
+    assert!(link.into_option().is_none());
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unlink_project(contract_hash(1))
+        .returns(ExpectError(4, "Project not linked"))
+        .run();
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+fn provenance(kind: proxy::AuditKind, model_id: &[u8], prompt_template_hash: &[u8]) -> proxy::AuditProvenance<StaticApi> {
+    proxy::AuditProvenance {
+        kind,
+        model_id: ManagedBuffer::new_from_bytes(model_id),
+        model_version: ManagedBuffer::new(),
+        prompt_template_hash: ManagedBuffer::new_from_bytes(prompt_template_hash),
+        tool_version: ManagedBuffer::from("auditfi 1.0"),
+    }
+}
+
+fn register_with_provenance(
+    state: &mut RegistryTestState,
+    hash_byte: u8,
+    provenance: proxy::AuditProvenance<StaticApi>,
+) {
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_chain_audit(
+            ManagedBuffer::from("multiversx"),
+            proxy::HashAlgorithm::MultiversXCodeHash,
+            contract_hash(hash_byte),
+            4u8,
+            ManagedBuffer::from("audit"),
+            OptionalValue::Some(provenance),
+        )
+        .run();
+}
+
+fn register_with_provenance_expect_error(
+    state: &mut RegistryTestState,
+    provenance: proxy::AuditProvenance<StaticApi>,
+    message: &str,
+) {
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_chain_audit(
+            ManagedBuffer::from("multiversx"),
+            proxy::HashAlgorithm::MultiversXCodeHash,
+            contract_hash(1),
+            4u8,
+            ManagedBuffer::from("audit"),
+            OptionalValue::Some(provenance),
+        )
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn audits_of_kind(state: &mut RegistryTestState, kind: proxy::AuditKind) -> Vec<ManagedByteArray<StaticApi, 32>> {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_all_audits(0usize, 10usize, OptionalValue::Some(kind))
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|item| item.into_tuple().0)
+        .collect()
+}
+
+#[test]
+fn provenance_is_stored_with_the_audit() {
+    let mut state = RegistryTestState::new();
+    register_with_provenance(&mut state, 1, provenance(proxy::AuditKind::Ai, b"model-x", &[7; 32]));
+
+    let audit = state.latest_audit(1);
+    assert_eq!(audit.provenance.kind, proxy::AuditKind::Ai);
+    assert_eq!(audit.provenance.model_id, ManagedBuffer::from("model-x"));
+    assert_eq!(audit.provenance.prompt_template_hash, ManagedBuffer::new_from_bytes(&[7; 32]));
+
+    // The legacy endpoint records an unspecified provenance
+    state.register_audit(AUDITOR, 2, 3, "manual");
+    assert_eq!(state.latest_audit(2).provenance.kind, proxy::AuditKind::Unspecified);
+}
+
+#[test]
+fn list_views_filter_by_kind() {
+    let mut state = RegistryTestState::new();
+    register_with_provenance(&mut state, 1, provenance(proxy::AuditKind::Ai, b"model-x", b""));
+    register_with_provenance(&mut state, 2, provenance(proxy::AuditKind::Human, b"", b""));
+    register_with_provenance(&mut state, 3, provenance(proxy::AuditKind::Hybrid, b"model-x", b""));
+
+    assert_eq!(audits_of_kind(&mut state, proxy::AuditKind::Ai), vec![contract_hash(1)]);
+    assert_eq!(audits_of_kind(&mut state, proxy::AuditKind::Human), vec![contract_hash(2)]);
+
+    let human_audits = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contract_audits(contract_hash(3), OptionalValue::Some(proxy::AuditKind::Human))
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(human_audits.into_iter().count(), 0);
+}
+
+#[test]
+fn provenance_is_validated() {
+    let mut state = RegistryTestState::new();
+    register_with_provenance_expect_error(
+        &mut state,
+        provenance(proxy::AuditKind::Human, b"model-x", b""),
+        "Model details require an AI or hybrid audit",
+    );
+    register_with_provenance_expect_error(
+        &mut state,
+        provenance(proxy::AuditKind::Ai, b"", b""),
+        "Model id cannot be empty",
+    );
+    register_with_provenance_expect_error(
+        &mut state,
+        provenance(proxy::AuditKind::Ai, &[b'm'; 65], b""),
+        "Provenance field too long",
+    );
+    register_with_provenance_expect_error(
+        &mut state,
+        provenance(proxy::AuditKind::Hybrid, b"model-x", &[7; 31]),
+        "Prompt template hash must be 32 bytes",
+    );
+}
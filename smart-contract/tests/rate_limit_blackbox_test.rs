@@ -0,0 +1,133 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const WINDOW_DURATION: u64 = 3_600;
+const COOLDOWN: u64 = 600;
+
+fn rate_limits(max_audits_per_window: u32, window_duration: u64) -> proxy::RateLimits {
+    proxy::RateLimits {
+        max_audits_per_window,
+        window_duration,
+        same_contract_cooldown: COOLDOWN,
+    }
+}
+
+fn set_rate_limits(state: &mut RegistryTestState, max_audits_per_window: u32) {
+    state.run_admin_action(proxy::AdminAction::SetRateLimits(rate_limits(
+        max_audits_per_window,
+        WINDOW_DURATION,
+    )));
+}
+
+fn remaining_quota(state: &mut RegistryTestState, auditor: TestAddress) -> (u32, u64) {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_remaining_quota(auditor.to_managed_address())
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple()
+}
+
+fn register_expect_error(state: &mut RegistryTestState, hash_byte: u8, message: &str) {
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audit(contract_hash(hash_byte), 3u8, ManagedBuffer::from("audit"))
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+#[test]
+fn registration_is_unlimited_without_configured_limits() {
+    let mut state = RegistryTestState::new();
+    let limits = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_rate_limits()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(limits.max_audits_per_window, 0);
+    assert_eq!(limits.same_contract_cooldown, 0);
+
+    // Same block, same contract, repeatedly
+    for _ in 0..5 {
+        state.register_audit(AUDITOR, 1, 3, "audit");
+    }
+    assert_eq!(remaining_quota(&mut state, AUDITOR), (u32::MAX, 0));
+}
+
+#[test]
+fn registration_stops_at_the_window_quota() {
+    let mut state = RegistryTestState::new();
+    set_rate_limits(&mut state, 2);
+    let window_end = state.timestamp + WINDOW_DURATION;
+    assert_eq!(remaining_quota(&mut state, AUDITOR), (2, window_end));
+
+    state.register_audit(AUDITOR, 1, 3, "first");
+    state.register_audit(AUDITOR, 2, 3, "second");
+    assert_eq!(remaining_quota(&mut state, AUDITOR), (0, window_end));
+    register_expect_error(&mut state, 3, "Audit quota exceeded");
+
+    // Quotas are per auditor
+    state.register_audit(OTHER_AUDITOR, 3, 3, "other");
+
+    state.advance_time(WINDOW_DURATION);
+    assert_eq!(remaining_quota(&mut state, AUDITOR), (2, state.timestamp + WINDOW_DURATION));
+    state.register_audit(AUDITOR, 3, 3, "third");
+}
+
+#[test]
+fn same_contract_cooldown() {
+    let mut state = RegistryTestState::new();
+    set_rate_limits(&mut state, 0);
+
+    state.register_audit(AUDITOR, 1, 3, "first");
+    let next_allowed = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_next_allowed_audit_time(AUDITOR.to_managed_address(), contract_hash(1))
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(next_allowed, state.timestamp + COOLDOWN);
+
+    register_expect_error(&mut state, 1, "Contract audited too recently");
+    state.register_audit(OTHER_AUDITOR, 1, 3, "second opinion");
+
+    state.advance_time(COOLDOWN);
+    state.register_audit(AUDITOR, 1, 3, "re-audit");
+}
+
+#[test]
+fn set_rate_limits_is_validated() {
+    let mut state = RegistryTestState::new();
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .queue_admin_action(proxy::AdminAction::SetRateLimits(rate_limits(2, WINDOW_DURATION)))
+        .returns(ExpectError(4, "Only owner can manage admin actions"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .queue_admin_action(proxy::AdminAction::SetRateLimits(rate_limits(2, 0)))
+        .returns(ExpectError(4, "Window duration cannot be zero"))
+        .run();
+}
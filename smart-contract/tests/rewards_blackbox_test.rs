@@ -0,0 +1,249 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const REGISTRATION_FEE: u64 = 100;
+
+fn register_paid_audit(state: &mut RegistryTestState, auditor: TestAddress, hash_byte: u8) {
+    state
+        .world
+        .tx()
+        .from(auditor)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audit(contract_hash(hash_byte), 4u8, ManagedBuffer::from("paid"))
+        .egld(REGISTRATION_FEE)
+        .run();
+}
+
+fn claimable(state: &mut RegistryTestState, auditor: TestAddress) -> u64 {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_claimable_rewards(auditor.to_managed_address())
+        .returns(ReturnsResult)
+        .run()
+        .to_u64()
+        .unwrap()
+}
+
+fn total_claimable(state: &mut RegistryTestState) -> u64 {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_total_claimable_rewards()
+        .returns(ReturnsResult)
+        .run()
+        .to_u64()
+        .unwrap()
+}
+
+fn claim(state: &mut RegistryTestState, auditor: TestAddress) {
+    state
+        .world
+        .tx()
+        .from(auditor)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .claim_rewards()
+        .run();
+}
+
+fn add_moderator(state: &mut RegistryTestState) {
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .add_moderator(USER.to_managed_address())
+        .run();
+}
+
+fn set_audit_hidden(state: &mut RegistryTestState, hash_byte: u8, hidden: bool) {
+    let tx = state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy);
+    if hidden {
+        tx.hide_audit(contract_hash(hash_byte), 1usize, proxy::ModerationReason::Spam)
+            .run();
+    } else {
+        tx.unhide_audit(contract_hash(hash_byte), 1usize, proxy::ModerationReason::Spam)
+            .run();
+    }
+}
+
+fn revoke(state: &mut RegistryTestState, auditor: TestAddress, hash_byte: u8) {
+    state
+        .world
+        .tx()
+        .from(auditor)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .revoke_audit(contract_hash(hash_byte), 1usize)
+        .run();
+}
+
+fn setup(share_bps: u32) -> RegistryTestState {
+    let mut state = RegistryTestState::new();
+    state.run_admin_action(proxy::AdminAction::SetRegistrationFee(BigUint::from(REGISTRATION_FEE)));
+    state.run_admin_action(proxy::AdminAction::SetAuditorShare(share_bps));
+    state
+}
+
+#[test]
+fn fees_are_shared_pro_rata_to_registered_audits() {
+    let mut state = setup(5_000);
+    // Nobody has audits yet, so the first fee stays with the treasury
+    register_paid_audit(&mut state, AUDITOR, 1);
+    assert_eq!(total_claimable(&mut state), 0);
+
+    register_paid_audit(&mut state, OTHER_AUDITOR, 2);
+    register_paid_audit(&mut state, THIRD_AUDITOR, 3);
+    assert_eq!(claimable(&mut state, AUDITOR), 75);
+    assert_eq!(claimable(&mut state, OTHER_AUDITOR), 25);
+    assert_eq!(claimable(&mut state, THIRD_AUDITOR), 0);
+    assert_eq!(total_claimable(&mut state), 100);
+
+    claim(&mut state, AUDITOR);
+    state
+        .world
+        .check_account(AUDITOR)
+        .balance(INITIAL_BALANCE - REGISTRATION_FEE + 75);
+    assert_eq!(claimable(&mut state, AUDITOR), 0);
+    assert_eq!(total_claimable(&mut state), 25);
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .claim_rewards()
+        .returns(ExpectError(4, "No rewards to claim"))
+        .run();
+
+    // Rewards still owed to auditors are not part of the treasury
+    state.run_admin_action(proxy::AdminAction::Withdraw);
+    state.world.check_account(REGISTRY).balance(25u64);
+    claim(&mut state, OTHER_AUDITOR);
+    state.world.check_account(REGISTRY).balance(0u64);
+}
+
+#[test]
+fn rounding_remainders_are_carried_forward() {
+    let mut state = setup(10_000);
+    for (hash_byte, auditor) in [
+        AUDITOR,
+        AUDITOR,
+        AUDITOR,
+        AUDITOR,
+        OTHER_AUDITOR,
+        THIRD_AUDITOR,
+        AUDITOR,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        register_paid_audit(&mut state, auditor, hash_byte as u8 + 1);
+    }
+
+    // Six fees split over one to six audits; only fractions of a unit are still owed
+    assert_eq!(total_claimable(&mut state), 600);
+    assert_eq!(claimable(&mut state, AUDITOR), 546);
+    assert_eq!(claimable(&mut state, OTHER_AUDITOR), 36);
+    assert_eq!(claimable(&mut state, THIRD_AUDITOR), 16);
+
+    for auditor in [AUDITOR, OTHER_AUDITOR, THIRD_AUDITOR] {
+        claim(&mut state, auditor);
+    }
+    // The leftover fractions stay locked for the auditors and add up with later fees
+    assert_eq!(total_claimable(&mut state), 2);
+    state.run_admin_action(proxy::AdminAction::Withdraw);
+    state.world.check_account(REGISTRY).balance(2u64);
+}
+
+#[test]
+fn audits_registered_without_a_fee_earn_no_share() {
+    let mut state = RegistryTestState::new();
+    state.run_admin_action(proxy::AdminAction::SetAuditorShare(5_000));
+    state.register_audit(AUDITOR, 1, 4, "free");
+
+    state.run_admin_action(proxy::AdminAction::SetRegistrationFee(BigUint::from(REGISTRATION_FEE)));
+    register_paid_audit(&mut state, OTHER_AUDITOR, 2);
+    register_paid_audit(&mut state, THIRD_AUDITOR, 3);
+    assert_eq!(claimable(&mut state, AUDITOR), 0);
+    assert_eq!(claimable(&mut state, OTHER_AUDITOR), 50);
+    assert_eq!(total_claimable(&mut state), 50);
+}
+
+#[test]
+fn revoked_and_hidden_audits_stop_earning() {
+    let mut state = setup(10_000);
+    add_moderator(&mut state);
+    register_paid_audit(&mut state, AUDITOR, 1);
+    register_paid_audit(&mut state, OTHER_AUDITOR, 2);
+    assert_eq!(claimable(&mut state, AUDITOR), 100);
+
+    revoke(&mut state, AUDITOR, 1);
+    register_paid_audit(&mut state, THIRD_AUDITOR, 3);
+    assert_eq!(claimable(&mut state, AUDITOR), 100);
+    assert_eq!(claimable(&mut state, OTHER_AUDITOR), 100);
+
+    set_audit_hidden(&mut state, 2, true);
+    register_paid_audit(&mut state, THIRD_AUDITOR, 4);
+    assert_eq!(claimable(&mut state, OTHER_AUDITOR), 100);
+    assert_eq!(claimable(&mut state, THIRD_AUDITOR), 100);
+
+    // Unhiding restores the share, but not for an audit revoked while hidden
+    set_audit_hidden(&mut state, 2, false);
+    set_audit_hidden(&mut state, 4, true);
+    revoke(&mut state, THIRD_AUDITOR, 4);
+    set_audit_hidden(&mut state, 4, false);
+    register_paid_audit(&mut state, AUDITOR, 5);
+    assert_eq!(claimable(&mut state, AUDITOR), 100);
+    assert_eq!(claimable(&mut state, OTHER_AUDITOR), 150);
+    assert_eq!(claimable(&mut state, THIRD_AUDITOR), 150);
+}
+
+#[test]
+fn blocked_auditors_earn_nothing_until_unblocked() {
+    let mut state = setup(10_000);
+    add_moderator(&mut state);
+    register_paid_audit(&mut state, AUDITOR, 1);
+    register_paid_audit(&mut state, OTHER_AUDITOR, 2);
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .block_auditor(AUDITOR.to_managed_address(), proxy::ModerationReason::Spam)
+        .run();
+    register_paid_audit(&mut state, THIRD_AUDITOR, 3);
+    // Rewards earned before the block stay claimable
+    assert_eq!(claimable(&mut state, AUDITOR), 100);
+    assert_eq!(claimable(&mut state, OTHER_AUDITOR), 100);
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unblock_auditor(AUDITOR.to_managed_address(), proxy::ModerationReason::AppealAccepted)
+        .run();
+    register_paid_audit(&mut state, OTHER_AUDITOR, 4);
+    assert_eq!(claimable(&mut state, AUDITOR), 133);
+    assert_eq!(claimable(&mut state, OTHER_AUDITOR), 133);
+    assert_eq!(claimable(&mut state, THIRD_AUDITOR), 33);
+    assert_eq!(total_claimable(&mut state), 300);
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+fn single_entry_batch(
+    hash_byte: u8,
+) -> MultiValueEncoded<StaticApi, MultiValue3<ManagedByteArray<StaticApi, 32>, u8, ManagedBuffer<StaticApi>>> {
+    let mut audits = MultiValueEncoded::new();
+    audits.push((contract_hash(hash_byte), 5u8, ManagedBuffer::from("our own code")).into());
+    audits
+}
+
+#[test]
+fn self_audits_are_flagged_by_default() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    assert!(!state.latest_audit(1).self_attested);
+
+    state.register_audit(PROJECT_OWNER, 1, 5, "our own code");
+    assert!(state.latest_audit(1).self_attested);
+
+    // The deployed contract counts as the project too
+    state
+        .world
+        .tx()
+        .from(PROJECT_SC)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audit(contract_hash(1), 5u8, ManagedBuffer::from("attested by the contract"))
+        .run();
+    assert!(state.latest_audit(1).self_attested);
+
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audits_batch(single_entry_batch(1))
+        .run();
+    assert!(state.latest_audit(1).self_attested);
+
+    state.register_audit(OTHER_AUDITOR, 1, 4, "independent");
+    assert!(!state.latest_audit(1).self_attested);
+}
+
+#[test]
+fn self_audits_can_be_rejected() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    state.run_admin_action(proxy::AdminAction::SetRejectSelfAudits(true));
+
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audit(contract_hash(1), 5u8, ManagedBuffer::from("our own code"))
+        .returns(ExpectError(4, "Self-audits are not allowed"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audits_batch(single_entry_batch(1))
+        .returns(ExpectError(4, "Self-audits are not allowed"))
+        .run();
+
+    // Only the linked contract is affected
+    state.register_audit(PROJECT_OWNER, 2, 5, "someone else's code");
+    state.register_audit(OTHER_AUDITOR, 1, 4, "independent");
+}
+
+#[test]
+fn unlinked_contracts_are_not_checked() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unlink_project(contract_hash(1))
+        .run();
+
+    state.register_audit(PROJECT_OWNER, 1, 5, "our own code");
+    assert!(!state.latest_audit(1).self_attested);
+}
@@ -8,3 +8,18 @@ fn world() -> ScenarioWorld {
 fn empty_go() {
     world().run("scenarios/smart_contract.scen.json");
 }
+
+#[test]
+fn events_go() {
+    world().run("scenarios/events.scen.json");
+}
+
+#[test]
+fn storage_layout_go() {
+    world().run("scenarios/storage_layout.scen.json");
+}
+
+#[test]
+fn legacy_migration_go() {
+    world().run("scenarios/legacy_migration.scen.json");
+}
@@ -12,3 +12,18 @@ fn world() -> ScenarioWorld {
 fn empty_rs() {
     world().run("scenarios/smart_contract.scen.json");
 }
+
+#[test]
+fn events_rs() {
+    world().run("scenarios/events.scen.json");
+}
+
+#[test]
+fn storage_layout_rs() {
+    world().run("scenarios/storage_layout.scen.json");
+}
+
+#[test]
+fn legacy_migration_rs() {
+    world().run("scenarios/legacy_migration.scen.json");
+}
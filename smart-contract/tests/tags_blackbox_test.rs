@@ -0,0 +1,246 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+fn tag_list(tags: &[&str]) -> MultiValueEncoded<StaticApi, ManagedBuffer<StaticApi>> {
+    let mut result = MultiValueEncoded::new();
+    for tag in tags {
+        result.push(ManagedBuffer::from(*tag));
+    }
+    result
+}
+
+fn add_tags(state: &mut RegistryTestState, from: TestAddress, hash_byte: u8, tags: &[&str]) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .add_contract_tags(contract_hash(hash_byte), tag_list(tags))
+        .run();
+}
+
+fn add_tags_expect_error(
+    state: &mut RegistryTestState,
+    from: TestAddress,
+    hash_byte: u8,
+    tags: &[&str],
+    message: &str,
+) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .add_contract_tags(contract_hash(hash_byte), tag_list(tags))
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn set_category(
+    state: &mut RegistryTestState,
+    from: TestAddress,
+    hash_byte: u8,
+    category: proxy::ContractCategory,
+) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .set_contract_category(contract_hash(hash_byte), category)
+        .run();
+}
+
+fn contracts_by_tag(
+    state: &mut RegistryTestState,
+    tag: &str,
+    start_index: usize,
+    limit: usize,
+) -> usize {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contracts_by_tag(ManagedBuffer::from(tag), start_index, limit)
+        .returns(ReturnsResult)
+        .run()
+        .len()
+}
+
+fn total_by_category(state: &mut RegistryTestState, category: proxy::ContractCategory) -> usize {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_total_contracts_by_category(category)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn auditors_tag_their_contracts() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "audit");
+    state.register_audit(AUDITOR, 2, 4, "audit");
+    add_tags(&mut state, AUDITOR, 1, &["defi", "lending", "defi"]);
+    add_tags(&mut state, AUDITOR, 2, &["defi"]);
+
+    let tags = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contract_tags(contract_hash(1))
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(tags.len(), 2);
+    assert_eq!(contracts_by_tag(&mut state, "defi", 0, 10), 2);
+    assert_eq!(contracts_by_tag(&mut state, "defi", 1, 10), 1);
+    assert_eq!(contracts_by_tag(&mut state, "lending", 0, 10), 1);
+
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .remove_contract_tags(contract_hash(1), tag_list(&["defi", "unknown"]))
+        .run();
+    let total = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_total_contracts_by_tag(ManagedBuffer::from("defi"))
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(total, 1);
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contracts_by_tag(ManagedBuffer::from("defi"), 1usize, 10usize)
+        .returns(ExpectError(4, "Start index out of bounds"))
+        .run();
+}
+
+#[test]
+fn changing_the_category_moves_the_contract() {
+    let mut state = RegistryTestState::new();
+    state.link_project(1);
+    set_category(&mut state, PROJECT_OWNER, 1, proxy::ContractCategory::DeFi);
+    assert_eq!(
+        total_by_category(&mut state, proxy::ContractCategory::DeFi),
+        1
+    );
+
+    set_category(&mut state, OWNER, 1, proxy::ContractCategory::Bridge);
+    assert_eq!(
+        total_by_category(&mut state, proxy::ContractCategory::DeFi),
+        0
+    );
+    assert_eq!(
+        total_by_category(&mut state, proxy::ContractCategory::Bridge),
+        1
+    );
+
+    let category = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_contract_category(contract_hash(1))
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(
+        category.into_option(),
+        Some(proxy::ContractCategory::Bridge)
+    );
+}
+
+#[test]
+fn visible_views_skip_hidden_contracts() {
+    let mut state = RegistryTestState::new();
+    state.register_audit(AUDITOR, 1, 4, "audit");
+    state.register_audit(AUDITOR, 2, 4, "audit");
+    for hash_byte in [1, 2] {
+        add_tags(&mut state, AUDITOR, hash_byte, &["defi"]);
+        set_category(&mut state, AUDITOR, hash_byte, proxy::ContractCategory::DeFi);
+    }
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .hide_contract(contract_hash(1), proxy::ModerationReason::Spam)
+        .run();
+
+    assert_eq!(contracts_by_tag(&mut state, "defi", 0, 10), 2);
+    let by_tag: Vec<_> = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_visible_contracts_by_tag(ManagedBuffer::from("defi"), 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(by_tag, vec![contract_hash(2)]);
+
+    let by_category: Vec<_> = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_visible_contracts_by_category(proxy::ContractCategory::DeFi, 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(by_category, vec![contract_hash(2)]);
+}
+
+#[test]
+fn tag_rejections() {
+    let mut state = RegistryTestState::new();
+    add_tags_expect_error(
+        &mut state,
+        AUDITOR,
+        1,
+        &["defi"],
+        "No audits found for this contract",
+    );
+
+    state.register_audit(AUDITOR, 1, 4, "audit");
+    add_tags_expect_error(
+        &mut state,
+        USER,
+        1,
+        &["defi"],
+        "Not allowed to curate this contract",
+    );
+    add_tags_expect_error(&mut state, AUDITOR, 1, &[""], "Invalid tag length");
+    add_tags_expect_error(
+        &mut state,
+        AUDITOR,
+        1,
+        &[&"a".repeat(33)],
+        "Invalid tag length",
+    );
+    add_tags_expect_error(&mut state, AUDITOR, 1, &["DeFi"], "Invalid tag characters");
+
+    let tags: Vec<String> = (0..11).map(|i| format!("tag-{i}")).collect();
+    let tags: Vec<&str> = tags.iter().map(String::as_str).collect();
+    add_tags_expect_error(&mut state, AUDITOR, 1, &tags, "Too many tags");
+    add_tags(&mut state, AUDITOR, 1, &tags[..10]);
+}
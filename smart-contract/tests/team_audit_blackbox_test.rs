@@ -0,0 +1,238 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+fn propose(state: &mut RegistryTestState, co_auditors: &[TestAddress]) -> u64 {
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .propose_team_audit(
+            contract_hash(1),
+            4u8,
+            ManagedBuffer::from("team findings"),
+            MultiValueVec::from(
+                co_auditors
+                    .iter()
+                    .map(|co_auditor| co_auditor.to_managed_address())
+                    .collect::<Vec<_>>(),
+            ),
+        )
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn propose_expect_error(state: &mut RegistryTestState, stars: u8, co_auditors: &[TestAddress], message: &str) {
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .propose_team_audit(
+            contract_hash(1),
+            stars,
+            ManagedBuffer::from("team findings"),
+            MultiValueVec::from(
+                co_auditors
+                    .iter()
+                    .map(|co_auditor| co_auditor.to_managed_address())
+                    .collect::<Vec<_>>(),
+            ),
+        )
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn sign(state: &mut RegistryTestState, from: TestAddress, draft_id: u64) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .sign_team_audit(draft_id)
+        .run();
+}
+
+fn sign_expect_error(state: &mut RegistryTestState, from: TestAddress, draft_id: u64, message: &str) {
+    state
+        .world
+        .tx()
+        .from(from)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .sign_team_audit(draft_id)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn audit_signers(state: &mut RegistryTestState) -> Vec<Address> {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_audit_signers(contract_hash(1), 1usize)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+#[test]
+fn team_audit_is_registered_once_everyone_signs() {
+    let mut state = RegistryTestState::new();
+    let draft_id = propose(&mut state, &[OTHER_AUDITOR, THIRD_AUDITOR]);
+    assert_eq!(draft_id, 1);
+
+    sign(&mut state, OTHER_AUDITOR, draft_id);
+    let signatures = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_team_audit_signatures(draft_id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(signatures, vec![OTHER_AUDITOR.to_address()]);
+
+    sign(&mut state, THIRD_AUDITOR, draft_id);
+    let audit = state.latest_audit(1);
+    assert_eq!(audit.stars, 4);
+    assert_eq!(audit.auditor, AUDITOR.to_managed_address());
+    assert_eq!(
+        audit_signers(&mut state),
+        vec![
+            AUDITOR.to_address(),
+            OTHER_AUDITOR.to_address(),
+            THIRD_AUDITOR.to_address()
+        ]
+    );
+
+    // The draft is gone once the audit is final
+    sign_expect_error(&mut state, OTHER_AUDITOR, draft_id, "Team audit not found");
+}
+
+#[test]
+fn team_audit_proposals_are_validated() {
+    let mut state = RegistryTestState::new();
+    propose_expect_error(&mut state, 6, &[OTHER_AUDITOR], "Stars must be between 0 and 5");
+    propose_expect_error(&mut state, 4, &[], "Team audit needs co-auditors");
+    propose_expect_error(&mut state, 4, &[OTHER_AUDITOR, AUDITOR], "Lead cannot co-sign");
+    propose_expect_error(&mut state, 4, &[OTHER_AUDITOR, OTHER_AUDITOR], "Duplicate co-auditor");
+    propose_expect_error(&mut state, 4, &[OTHER_AUDITOR; 11], "Too many co-auditors");
+
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .propose_team_audit(
+            contract_hash(1),
+            4u8,
+            ManagedBuffer::from("team findings"),
+            MultiValueVec::from(vec![OTHER_AUDITOR.to_managed_address()]),
+        )
+        .egld(1u64)
+        .returns(ExpectError(4, "Incorrect registration fee"))
+        .run();
+}
+
+#[test]
+fn team_audit_signatures_are_validated() {
+    let mut state = RegistryTestState::new();
+    let draft_id = propose(&mut state, &[OTHER_AUDITOR, THIRD_AUDITOR]);
+
+    sign_expect_error(&mut state, USER, draft_id, "Not a co-auditor of this audit");
+    sign_expect_error(&mut state, OTHER_AUDITOR, draft_id + 1, "Team audit not found");
+    sign(&mut state, OTHER_AUDITOR, draft_id);
+    sign_expect_error(&mut state, OTHER_AUDITOR, draft_id, "Already signed");
+}
+
+#[test]
+fn only_the_lead_can_cancel_a_team_audit() {
+    let mut state = RegistryTestState::new();
+    let draft_id = propose(&mut state, &[OTHER_AUDITOR]);
+
+    state
+        .world
+        .tx()
+        .from(OTHER_AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .cancel_team_audit(draft_id)
+        .returns(ExpectError(4, "Only the lead auditor can cancel"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .cancel_team_audit(draft_id)
+        .run();
+
+    sign_expect_error(&mut state, OTHER_AUDITOR, draft_id, "Team audit not found");
+}
+
+#[test]
+fn team_audits_cannot_be_amended() {
+    let mut state = RegistryTestState::new();
+    let draft_id = propose(&mut state, &[OTHER_AUDITOR]);
+    sign(&mut state, OTHER_AUDITOR, draft_id);
+
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .amend_audit(contract_hash(1), 1usize, 1u8, ManagedBuffer::from("lead only"))
+        .returns(ExpectError(4, "Team audits cannot be amended"))
+        .run();
+    assert_eq!(state.latest_audit(1).stars, 4);
+}
+
+#[test]
+fn co_signers_cannot_review_their_team_audit() {
+    let mut state = RegistryTestState::new();
+    let draft_id = propose(&mut state, &[OTHER_AUDITOR]);
+    sign(&mut state, OTHER_AUDITOR, draft_id);
+    state.register_audit(THIRD_AUDITOR, 2, 4, "reviewer history");
+
+    for co_signer in [AUDITOR, OTHER_AUDITOR] {
+        state
+            .world
+            .tx()
+            .from(co_signer)
+            .to(REGISTRY)
+            .typed(proxy::AuditRegistryProxy)
+            .review_audit(
+                contract_hash(1),
+                1usize,
+                proxy::ReviewVerdict::Endorse,
+                ManagedBuffer::from("ours"),
+            )
+            .returns(ExpectError(4, "Cannot review your own audit"))
+            .run();
+    }
+    state
+        .world
+        .tx()
+        .from(THIRD_AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .review_audit(
+            contract_hash(1),
+            1usize,
+            proxy::ReviewVerdict::Endorse,
+            ManagedBuffer::from("checked"),
+        )
+        .run();
+    assert_eq!(state.latest_audit(1).endorsements, 1);
+}
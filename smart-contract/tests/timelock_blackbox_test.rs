@@ -0,0 +1,230 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const TIMELOCK_GRACE_PERIOD: u64 = 1_209_600;
+const REGISTRATION_FEE: u64 = 100;
+
+fn queue(state: &mut RegistryTestState, action: proxy::AdminAction<StaticApi>) -> usize {
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .queue_admin_action(action)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn queue_expect_error(state: &mut RegistryTestState, action: proxy::AdminAction<StaticApi>, message: &str) {
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .queue_admin_action(action)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn execute(state: &mut RegistryTestState, action_id: usize) {
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .execute_admin_action(action_id)
+        .run();
+}
+
+fn execute_expect_error(state: &mut RegistryTestState, action_id: usize, message: &str) {
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .execute_admin_action(action_id)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn queued_action_ids(state: &mut RegistryTestState) -> Vec<usize> {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_queued_actions()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|entry| entry.into_tuple().0)
+        .collect()
+}
+
+fn register_paid_audit(state: &mut RegistryTestState, hash_byte: u8) {
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audit(contract_hash(hash_byte), 4u8, ManagedBuffer::from("paid"))
+        .egld(REGISTRATION_FEE)
+        .run();
+}
+
+#[test]
+fn withdraw_leaves_locked_rewards_in_the_contract() {
+    let mut state = RegistryTestState::new();
+    state.run_admin_action(proxy::AdminAction::SetRegistrationFee(BigUint::from(REGISTRATION_FEE)));
+    state.run_admin_action(proxy::AdminAction::SetAuditorShare(5_000));
+
+    // The first fee has no audits to reward, so all of it goes to the treasury
+    register_paid_audit(&mut state, 1);
+    register_paid_audit(&mut state, 2);
+    state.world.check_account(REGISTRY).balance(2 * REGISTRATION_FEE);
+
+    state.run_admin_action(proxy::AdminAction::Withdraw);
+    state
+        .world
+        .check_account(OWNER)
+        .balance(INITIAL_BALANCE + 2 * REGISTRATION_FEE - REGISTRATION_FEE / 2);
+    state.world.check_account(REGISTRY).balance(REGISTRATION_FEE / 2);
+}
+
+#[test]
+fn actions_run_between_the_delay_and_the_grace_period() {
+    let mut state = RegistryTestState::new();
+    let early = queue(&mut state, proxy::AdminAction::SetRejectSelfAudits(true));
+    let late = queue(&mut state, proxy::AdminAction::SetRejectSelfAudits(true));
+    assert_eq!(queued_action_ids(&mut state), vec![early, late]);
+
+    state.advance_time(TIMELOCK_DELAY - 1);
+    execute_expect_error(&mut state, early, "Timelock not elapsed");
+
+    state.advance_time(1);
+    execute(&mut state, early);
+    execute_expect_error(&mut state, early, "Action is not queued");
+    assert_eq!(queued_action_ids(&mut state), vec![late]);
+
+    state.advance_time(TIMELOCK_GRACE_PERIOD);
+    execute_expect_error(&mut state, late, "Action expired");
+    assert!(queued_action_ids(&mut state).is_empty());
+}
+
+#[test]
+fn cancelled_actions_cannot_run() {
+    let mut state = RegistryTestState::new();
+    let action_id = queue(&mut state, proxy::AdminAction::Withdraw);
+    state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .cancel_admin_action(action_id)
+        .returns(ExpectError(4, "Only owner can manage admin actions"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .cancel_admin_action(action_id)
+        .run();
+
+    state.advance_time(TIMELOCK_DELAY);
+    execute_expect_error(&mut state, action_id, "Action is not queued");
+    execute_expect_error(&mut state, action_id + 1, "Action not found");
+    let cancelled = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_admin_action(action_id)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(cancelled.status, proxy::AdminActionStatus::Cancelled);
+}
+
+#[test]
+fn timelock_delay_applies_to_later_actions() {
+    let mut state = RegistryTestState::new();
+    queue_expect_error(&mut state, proxy::AdminAction::SetTimelockDelay(3_599), "Timelock delay out of range");
+    queue_expect_error(
+        &mut state,
+        proxy::AdminAction::SetTimelockDelay(2_592_001),
+        "Timelock delay out of range",
+    );
+    queue_expect_error(&mut state, proxy::AdminAction::SetAuditorShare(10_001), "Auditor share out of range");
+
+    state.run_admin_action(proxy::AdminAction::SetTimelockDelay(3_600));
+    let action_id = queue(&mut state, proxy::AdminAction::SetRejectSelfAudits(true));
+    state.advance_time(3_600);
+    execute(&mut state, action_id);
+    let reject = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .reject_self_audits()
+        .returns(ReturnsResult)
+        .run();
+    assert!(reject);
+}
+
+#[test]
+fn coverage_configuration_is_timelocked() {
+    let mut state = RegistryTestState::new();
+    let terms = |premium_bps: u32| proxy::CoverageTerms {
+        min_stars: 4,
+        premium_bps,
+        protocol_fee_bps: 1_000,
+        claim_quorum: 1,
+    };
+    queue_expect_error(&mut state, proxy::AdminAction::SetCoverageTerms(terms(0)), "Premium out of range");
+
+    let action_id = queue(&mut state, proxy::AdminAction::SetCoverageTerms(terms(500)));
+    let configured = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_coverage_terms()
+        .returns(ReturnsResult)
+        .run();
+    assert!(configured.into_option().is_none());
+
+    state.advance_time(TIMELOCK_DELAY);
+    execute(&mut state, action_id);
+    let configured = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_coverage_terms()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(configured.into_option().unwrap().premium_bps, 500);
+
+    state.run_admin_action(proxy::AdminAction::AddArbiter(USER.to_managed_address()));
+    state.run_admin_action(proxy::AdminAction::AddArbiter(THIRD_AUDITOR.to_managed_address()));
+    state.run_admin_action(proxy::AdminAction::RemoveArbiter(USER.to_managed_address()));
+    let arbiters = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .arbiters()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(arbiters, vec![THIRD_AUDITOR.to_address()]);
+}
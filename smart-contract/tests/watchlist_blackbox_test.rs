@@ -0,0 +1,208 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::{imports::*, scenario_model::Log};
+
+fn watch(state: &mut RegistryTestState, watcher: TestAddress, hash_byte: u8) {
+    state
+        .world
+        .tx()
+        .from(watcher)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .watch(contract_hash(hash_byte))
+        .run();
+}
+
+fn watched_contracts(state: &mut RegistryTestState, watcher: TestAddress) -> Vec<ManagedByteArray<StaticApi, 32>> {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_watched_contracts(watcher.to_managed_address())
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect()
+}
+
+fn watchers(state: &mut RegistryTestState, start_index: usize, limit: usize) -> Vec<Address> {
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_watchers(contract_hash(1), start_index, limit)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+// Topics and data of the watchedContractActivity log, if one was emitted
+fn watch_activity(logs: &[Log]) -> Option<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+    logs.iter()
+        .find(|log| log.topics[0] == b"watchedContractActivity".to_vec())
+        .map(|log| (log.topics[2].clone(), log.topics[3].clone(), log.data[0].clone()))
+}
+
+#[test]
+fn contracts_can_be_watched_before_their_first_audit() {
+    let mut state = RegistryTestState::new();
+    watch(&mut state, USER, 1);
+    watch(&mut state, USER, 2);
+    watch(&mut state, PROJECT_OWNER, 1);
+
+    assert_eq!(watched_contracts(&mut state, USER), vec![contract_hash(1), contract_hash(2)]);
+    assert_eq!(watchers(&mut state, 0, 10), vec![USER.to_address(), PROJECT_OWNER.to_address()]);
+    assert_eq!(watchers(&mut state, 1, 10), vec![PROJECT_OWNER.to_address()]);
+    assert_eq!(watchers(&mut state, 0, 1), vec![USER.to_address()]);
+
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unwatch(contract_hash(1))
+        .run();
+    assert_eq!(watched_contracts(&mut state, USER), vec![contract_hash(2)]);
+    assert_eq!(watchers(&mut state, 0, 10), vec![PROJECT_OWNER.to_address()]);
+
+    let total_watchers = state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_total_watchers(contract_hash(2))
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(total_watchers, 1);
+}
+
+#[test]
+fn watch_rejections() {
+    let mut state = RegistryTestState::new();
+    watch(&mut state, USER, 1);
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .watch(contract_hash(1))
+        .returns(ExpectError(4, "Already watching this contract"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .unwatch(contract_hash(2))
+        .returns(ExpectError(4, "Not watching this contract"))
+        .run();
+    state
+        .world
+        .query()
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .get_watchers(contract_hash(1), 1usize, 10usize)
+        .returns(ExpectError(4, "Start index out of bounds"))
+        .run();
+
+    // Up to 50 watched contracts per address
+    for hash_byte in 2..=50u8 {
+        watch(&mut state, USER, hash_byte);
+    }
+    state
+        .world
+        .tx()
+        .from(USER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .watch(contract_hash(51))
+        .returns(ExpectError(4, "Too many watched contracts"))
+        .run();
+}
+
+#[test]
+fn watchers_are_notified_of_new_audits_and_amendments() {
+    let mut state = RegistryTestState::new();
+    let logs = state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audit(contract_hash(1), 3u8, ManagedBuffer::from("unwatched"))
+        .returns(ReturnsLogs)
+        .run();
+    assert_eq!(watch_activity(&logs), None);
+
+    watch(&mut state, USER, 1);
+    watch(&mut state, PROJECT_OWNER, 1);
+    let logs = state
+        .world
+        .tx()
+        .from(OTHER_AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .register_audit(contract_hash(1), 4u8, ManagedBuffer::from("watched"))
+        .returns(ReturnsLogs)
+        .run();
+    // Audit 2, NewAudit, two watchers
+    assert_eq!(watch_activity(&logs), Some((vec![2], vec![], vec![2])));
+
+    let logs = state
+        .world
+        .tx()
+        .from(AUDITOR)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .amend_audit(contract_hash(1), 1usize, 2u8, ManagedBuffer::from("amended"))
+        .returns(ReturnsLogs)
+        .run();
+    assert_eq!(watch_activity(&logs), Some((vec![1], vec![1], vec![2])));
+}
+
+#[test]
+fn watchers_are_notified_of_disputes_only() {
+    let mut state = RegistryTestState::new();
+    watch(&mut state, USER, 1);
+    state.link_project(1);
+
+    let logs = state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .respond_to_audit(
+            contract_hash(1),
+            1usize,
+            proxy::ResponseStatus::Acknowledged,
+            ManagedBuffer::from("noted"),
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .returns(ReturnsLogs)
+        .run();
+    assert_eq!(watch_activity(&logs), None);
+
+    let logs = state
+        .world
+        .tx()
+        .from(PROJECT_OWNER)
+        .to(REGISTRY)
+        .typed(proxy::AuditRegistryProxy)
+        .respond_to_audit(
+            contract_hash(1),
+            1usize,
+            proxy::ResponseStatus::Disputed,
+            ManagedBuffer::from("wrong"),
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .returns(ReturnsLogs)
+        .run();
+    assert_eq!(watch_activity(&logs), Some((vec![1], vec![2], vec![1])));
+}
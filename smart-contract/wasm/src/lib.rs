@@ -5,9 +5,10 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            8
+// Upgrade:                              1
+// Endpoints:                          147
 // Async Callback (empty):               1
-// Total number of exported functions:  10
+// Total number of exported functions: 150
 
 #![no_std]
 
@@ -18,14 +19,154 @@ multiversx_sc_wasm_adapter::endpoints! {
     smart_contract
     (
         init => init
+        upgrade => upgrade
         register_audit => register_audit
+        register_chain_audit => register_chain_audit
+        register_audits_batch => register_audits_batch
+        migrate_legacy_audits => migrate_legacy_audits
+        amend_audit => amend_audit
+        revoke_audit => revoke_audit
+        propose_team_audit => propose_team_audit
+        sign_team_audit => sign_team_audit
+        cancel_team_audit => cancel_team_audit
+        commit_audit => commit_audit
+        publish_audit => publish_audit
         get_all_audits => get_all_audits
+        getVisibleAudits => get_visible_audits
         getTotalContracts => get_total_contracts
+        getAllAuditsByChain => get_all_audits_by_chain
+        getVisibleAuditsByChain => get_visible_audits_by_chain
+        getTotalContractsByChain => get_total_contracts_by_chain
+        getContractKey => get_contract_key
+        getContractNamespace => get_contract_namespace
         getContractAudits => get_contract_audits
+        getVisibleContractAudits => get_visible_contract_audits
         getAuditorHistory => get_auditor_history
+        getVisibleAuditorHistory => get_visible_auditor_history
         getLatestAudit => get_latest_audit
-        withdraw => withdraw
+        getLatestVisibleAudit => get_latest_visible_audit
+        isAuditValid => is_audit_valid
         getOwner => owner
+        getKnownChains => known_chains
+        isContractHidden => hidden_contract
+        getBlockedAuditors => blocked_auditors
+        getRegistrationFee => registration_fee
+        getAuditValidityPeriod => audit_validity_period
+        isAdmissionRequired => admission_required
+        getAdmittedAuditors => admitted_auditors
+        getMaxSummaryLength => get_max_summary_length
+        getLockedFunds => locked_funds
+        create_bounty => create_bounty
+        fund_bounty => fund_bounty
+        submit_vulnerability_report => submit_vulnerability_report
+        approve_report => approve_report
+        reject_report => reject_report
+        close_bounty => close_bounty
+        getBountyPool => get_bounty_pool
+        getBountyReports => get_bounty_reports
+        getBountyPoolReports => get_bounty_pool_reports
+        getActiveBounties => bounty_contracts
+        set_audit_scope => set_audit_scope
+        getAuditScope => get_audit_scope
+        add_moderator => add_moderator
+        remove_moderator => remove_moderator
+        hide_audit => hide_audit
+        unhide_audit => unhide_audit
+        hide_contract => hide_contract
+        unhide_contract => unhide_contract
+        block_auditor => block_auditor
+        unblock_auditor => unblock_auditor
+        isAuditHidden => is_audit_hidden
+        getModerationLogLength => get_moderation_log_length
+        getModerationLog => get_moderation_log
+        getModerators => moderators
+        getRemainingQuota => get_remaining_quota
+        getNextAllowedAuditTime => get_next_allowed_audit_time
+        getRateLimits => get_rate_limits
+        review_audit => review_audit
+        getAuditReviews => get_audit_reviews
+        getAuditorReputation => get_auditor_reputation
+        set_governance_token => set_governance_token
+        vote_on_audit => vote_on_audit
+        unlock_votes => unlock_votes
+        getVotingWindowEnd => get_voting_window_end
+        getAuditVotes => get_audit_votes
+        getVoteLock => get_vote_lock
+        getGovernanceToken => governance_token
+        getVotingPeriod => voting_period
+        getAuditCommitment => get_audit_commitment
+        getPendingCommitments => get_pending_commitments
+        getPublishedCommitment => get_published_commitment
+        deposit_coverage_capital => deposit_coverage_capital
+        withdraw_coverage_capital => withdraw_coverage_capital
+        buy_coverage => buy_coverage
+        file_coverage_claim => file_coverage_claim
+        vote_on_claim => vote_on_claim
+        expire_coverage_claim => expire_coverage_claim
+        release_coverage => release_coverage
+        getCoverageTerms => get_coverage_terms
+        quotePremium => quote_premium
+        getCoveragePool => get_coverage_pool
+        getUnderwriterPosition => get_underwriter_position
+        getCoveragePolicy => get_coverage_policy
+        getCoverageClaim => get_coverage_claim
+        getContractPolicies => get_contract_policies
+        getArbiters => arbiters
+        propose => propose
+        vote_proposal => vote_proposal
+        execute_proposal => execute_proposal
+        veto_proposal => veto_proposal
+        getProposal => get_proposal
+        getProposalCount => get_proposal_count
+        getProposalStatus => get_proposal_status
+        hasVotedOnProposal => has_voted_on_proposal
+        link_project => link_project
+        unlink_project => unlink_project
+        respond_to_audit => respond_to_audit
+        getProjectLink => get_project_link
+        getAuditResponse => get_audit_response
+        getRemediationAudits => get_remediation_audits
+        getVisibleRemediationAudits => get_visible_remediation_audits
+        isRejectingSelfAudits => reject_self_audits
+        record_upgrade => record_upgrade
+        getContractLineage => get_contract_lineage
+        getLineageAudits => get_lineage_audits
+        getVisibleLineageAudits => get_visible_lineage_audits
+        set_contract_category => set_contract_category
+        add_contract_tags => add_contract_tags
+        remove_contract_tags => remove_contract_tags
+        getContractCategory => get_contract_category
+        getContractTags => get_contract_tags
+        getContractsByTag => get_contracts_by_tag
+        getVisibleContractsByTag => get_visible_contracts_by_tag
+        getTotalContractsByTag => get_total_contracts_by_tag
+        getContractsByCategory => get_contracts_by_category
+        getVisibleContractsByCategory => get_visible_contracts_by_category
+        getTotalContractsByCategory => get_total_contracts_by_category
+        getTeamAuditDraft => get_team_audit_draft
+        getTeamAuditSignatures => get_team_audit_signatures
+        getAuditSigners => get_audit_signers
+        getAuditsBetween => get_audits_between
+        getContractsByMinStars => get_contracts_by_min_stars
+        getRecentAudits => get_recent_audits
+        getTotalAuditLogEntries => get_total_audit_log_entries
+        getTotalContractsByStars => get_total_contracts_by_stars
+        claim_rewards => claim_rewards
+        getClaimableRewards => get_claimable_rewards
+        getTotalClaimableRewards => get_total_claimable_rewards
+        getAuditorShare => auditor_share_bps
+        queue_admin_action => queue_admin_action
+        cancel_admin_action => cancel_admin_action
+        execute_admin_action => execute_admin_action
+        getAdminAction => get_admin_action
+        getQueuedActions => get_queued_actions
+        getTimelockDelay => get_timelock_delay
+        getApprovedUpgrade => approved_upgrade
+        watch => watch
+        unwatch => unwatch
+        getWatchers => get_watchers
+        getTotalWatchers => get_total_watchers
+        getWatchedContracts => get_watched_contracts
     )
 }
 
@@ -14,10 +14,11 @@ export const AUDIT_REGISTRY_ABI = {
       name: "get_all_audits",
       inputs: [
         { type: "u32", name: "start_index" },
-        { type: "u32", name: "limit" }
+        { type: "u32", name: "limit" },
+        { type: "optional<AuditKind>", name: "kind" }
       ],
       outputs: [
-        { type: "multi<bytes32,u8,bytes,address,u64>" }
+        { type: "variadic<multi<bytes32,u8,bytes,address,u64,bool>>" }
       ]
     },
     {
@@ -27,10 +28,13 @@ export const AUDIT_REGISTRY_ABI = {
     },
     {
       name: "getContractAudits",
-      inputs: [{ type: "bytes32", name: "contract_hash" }],
+      inputs: [
+        { type: "bytes32", name: "contract_hash" },
+        { type: "optional<AuditKind>", name: "kind" }
+      ],
       outputs: [
         { 
-          type: "variadic<multi<u8,bytes,address,u64>>",
+          type: "variadic<Audit>",
           name: "audits"
         }
       ]
@@ -44,14 +48,31 @@ export const AUDIT_REGISTRY_ABI = {
       name: "getLatestAudit",
       inputs: [{ type: "bytes32", name: "contract_hash" }],
       outputs: [
-        { type: "tuple<u8,bytes,address,u64>", name: "audit" }
+        { type: "Audit", name: "audit" }
       ]
     },
+    // Treasury withdrawals go through the admin timelock: queue the Withdraw
+    // action (AdminAction variant 0), then execute it once the delay has passed
     {
-      name: "withdraw",
-      inputs: [],
+      name: "queue_admin_action",
+      inputs: [{ type: "AdminAction", name: "action" }],
+      outputs: [{ type: "u32" }]
+    },
+    {
+      name: "execute_admin_action",
+      inputs: [{ type: "u32", name: "action_id" }],
       outputs: []
     },
+    {
+      name: "cancel_admin_action",
+      inputs: [{ type: "u32", name: "action_id" }],
+      outputs: []
+    },
+    {
+      name: "getTimelockDelay",
+      inputs: [],
+      outputs: [{ type: "u64" }]
+    },
     {
       name: "getOwner",
       inputs: [],
@@ -65,6 +86,10 @@ export const AUDIT_REGISTRY_ABI = {
         { indexed: true, name: "contract_hash", type: "bytes32" },
         { indexed: true, name: "stars", type: "u8" },
         { indexed: true, name: "auditor", type: "address" },
+        { indexed: true, name: "self_attested", type: "bool" },
+        { indexed: true, name: "version", type: "u8" },
+        { indexed: true, name: "audit_index", type: "u32" },
+        { indexed: true, name: "timestamp", type: "u64" },
         { name: "summary", type: "bytes" }
       ]
     }
@@ -80,12 +105,30 @@ export const CONTRACT_ADDRESSES = {
 
 export type ChainKey = keyof typeof CONTRACT_ADDRESSES;
 
+// AuditKind variants, in discriminant order
+export type AuditKind = 'Unspecified' | 'Human' | 'Ai' | 'Hybrid';
+
 // Types for the Audit struct
 export interface Audit {
   stars: number;
   summary: string;
   auditor: string;
   timestamp: number;
+  chain_id: string;
+  hash_algorithm: 'MultiversXCodeHash' | 'EvmBytecodeKeccak256' | 'SourceHash';
+  provenance: {
+    kind: AuditKind;
+    model_id: string;
+    model_version: string;
+    prompt_template_hash: string;
+    tool_version: string;
+  };
+  endorsements: number;
+  contests: number;
+  project_response: 'NoResponse' | 'Acknowledged' | 'Disputed' | 'Fixed';
+  self_attested: boolean;
+  amended_at: number;
+  revoked: boolean;
 }
 
 // Utility function to convert ASCII text to hex for MultiversX